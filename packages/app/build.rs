@@ -0,0 +1,188 @@
+//! Keeps `operators::OPERATORS` and the enums it uses in line with the FPGA sources.
+//!
+//! The build fails when an opcode of `debug.veryl` decodes differently in Rust, or when
+//! `OpeKind` or `AddrMode` are declared in another order than in `operators.veryl`, since
+//! the FPGA encodes them by position. `memory_map::Region` is checked against
+//! `CpuMemoryMapPkg` the same way.
+
+use std::{fs, path::Path};
+
+const DEBUG_VERYL: &str = "../../veryls/FC2/HDL/cpu/debug.veryl";
+const OPERATORS_VERYL: &str = "../../veryls/FC2/HDL/cpu/pkgs/operators.veryl";
+const OPERATORS_RS: &str = "src/operators/mod.rs";
+const MODE_RS: &str = "src/middleware/mode/mod.rs";
+const MEMORY_MAP_VERYL: &str = "../../veryls/FC2/HDL/cpu/pkgs/cpuMemoryMap.veryl";
+const MEMORY_MAP_RS: &str = "src/memory_map/mod.rs";
+
+/// Position of a veryl variant that rust leaves out.
+const SKIPPED: &str = "_";
+
+/// `(ope_kind, addr_mode, cycles)` as written in either source.
+type Entry = (String, String, u8);
+
+fn main() {
+    for path in [
+        DEBUG_VERYL,
+        OPERATORS_VERYL,
+        OPERATORS_RS,
+        MODE_RS,
+        MEMORY_MAP_VERYL,
+        MEMORY_MAP_RS,
+    ] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+    // the crate is also built from copies without the veryl sources.
+    let (Ok(debug), Ok(operators), Ok(memory_map)) = (
+        fs::read_to_string(DEBUG_VERYL),
+        fs::read_to_string(OPERATORS_VERYL),
+        fs::read_to_string(MEMORY_MAP_VERYL),
+    ) else {
+        println!("cargo:warning=veryl sources not found, operator table is not checked.");
+        return;
+    };
+    let operators_rs = read(OPERATORS_RS);
+    let mode_rs = read(MODE_RS);
+
+    let mut errors = vec![];
+    for name in ["OpeKind", "AddrMode"] {
+        let veryl = variants(&operators, &format!("enum {}", name));
+        let rust = variants(
+            if name == "OpeKind" {
+                &operators_rs
+            } else {
+                &mode_rs
+            },
+            &format!("pub enum {}", name),
+        );
+        let matches = veryl.len() == rust.len()
+            && veryl
+                .iter()
+                .zip(&rust)
+                .all(|(veryl, rust)| rust == SKIPPED || veryl == rust);
+        if !matches {
+            errors.push(format!(
+                "{} differs from operators.veryl:\n  veryl {:?}\n  rust  {:?}",
+                name, veryl, rust
+            ));
+        }
+    }
+
+    // `wram_mirror` in veryl is `WramMirror` in rust.
+    let veryl: Vec<String> = variants(&memory_map, "enum CpuMemoryMapEnum")
+        .iter()
+        .map(|name| camel_case(name))
+        .collect();
+    let rust = variants(&read(MEMORY_MAP_RS), "pub enum Region");
+    if veryl != rust {
+        errors.push(format!(
+            "Region differs from cpuMemoryMap.veryl:\n  veryl {:?}\n  rust  {:?}",
+            veryl, rust
+        ));
+    }
+
+    let rust = rust_entries(&operators_rs);
+    if rust.len() != 256 {
+        errors.push(format!(
+            "OPERATORS has {} entries instead of 256",
+            rust.len()
+        ));
+    }
+    for (opcode, veryl) in veryl_entries(&debug) {
+        match rust.get(opcode) {
+            Some(rust) if *rust == veryl => {}
+            rust => errors.push(format!(
+                "opcode {:#04x} is {:?} in debug.veryl but {:?} in OPERATORS",
+                opcode, veryl, rust
+            )),
+        }
+    }
+    if !errors.is_empty() {
+        panic!("operator table out of date:\n{}", errors.join("\n"));
+    }
+}
+
+fn read(path: &str) -> String {
+    fs::read_to_string(Path::new(path)).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e))
+}
+
+/// Variant names of the enum declared on the line starting with `declaration`, by position.
+///
+/// Positions skipped with an explicit discriminant such as `Las = 77` are [`SKIPPED`].
+fn variants(source: &str, declaration: &str) -> Vec<String> {
+    let mut variants = vec![];
+    for line in source
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| !line.starts_with(declaration))
+        .skip(1)
+        .take_while(|line| !line.starts_with('}'))
+        .map(|line| line.split("//").next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+    {
+        let line = line.trim_end_matches(',');
+        let name = match line.split_once('=') {
+            Some((name, position)) => {
+                let position: usize = position.trim().parse().unwrap();
+                variants.resize(position.max(variants.len()), SKIPPED.to_string());
+                name.trim()
+            }
+            None => line,
+        };
+        variants.push(name.to_string());
+    }
+    variants
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// `assign operators[8'h00] = {OperatorsPkg::OpeKind::Brk, OperatorsPkg::AddrMode::Impl, 8'h7};`
+fn veryl_entries(source: &str) -> Vec<(usize, Entry)> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().strip_prefix("assign operators[8'h")?;
+            let (opcode, line) = line.split_once("] = {")?;
+            let (fields, _) = line.split_once('}')?;
+            let mut fields = fields.split(',').map(str::trim);
+            let kind = fields.next()?.strip_prefix("OperatorsPkg::OpeKind::")?;
+            let mode = fields.next()?.strip_prefix("OperatorsPkg::AddrMode::")?;
+            let cycles = fields.next()?.strip_prefix("8'h")?;
+            Some((
+                usize::from_str_radix(opcode, 16).ok()?,
+                (
+                    kind.to_string(),
+                    mode.to_string(),
+                    u8::from_str_radix(cycles, 16).ok()?,
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// `Operator::new(OpeKind::Brk, AddrMode::Impl, 7), // 0x00`, in table order.
+fn rust_entries(source: &str) -> Vec<Entry> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().strip_prefix("Operator::new(")?;
+            let (fields, _) = line.split_once(')')?;
+            let mut fields = fields.split(',').map(str::trim);
+            let kind = fields.next()?.strip_prefix("OpeKind::")?;
+            let mode = fields.next()?.strip_prefix("AddrMode::")?;
+            let cycles = fields.next()?.parse().ok()?;
+            Some((kind.to_string(), mode.to_string(), cycles))
+        })
+        .collect()
+}
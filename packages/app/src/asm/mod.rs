@@ -0,0 +1,450 @@
+//! Assembler for the short programs tests send, evaluated at compile time.
+//!
+//! ```text
+//! lda #$FF        immediate
+//! sta $33         zero page, `$33,X` and `$33,Y` for the indexed forms
+//! sta $0033       absolute, `$0033,X` and `$0033,Y` for the indexed forms
+//! jmp ($0200)     indirect, `($33,X)` and `($33),Y` for the indexed forms
+//! asl A           accumulator, or `asl` alone
+//! bcs *+6         relative to the opcode
+//! ```
+//!
+//! Numbers are `$` hex or decimal, and a zero page operand of an instruction without a zero
+//! page form is assembled as absolute. Mnemonics are the ones of [`OpeKind::mnemonic`], in
+//! any case, and documented opcodes are picked over illegal ones. Instructions are
+//! separated by `;` or new lines.
+//!
+//! Anything that does not assemble panics, which fails the build through [`inst!`] and
+//! [`program!`].
+//!
+//! [`OpeKind::mnemonic`]: crate::operators::OpeKind::mnemonic
+//! [`inst!`]: crate::inst
+//! [`program!`]: crate::program
+
+use crate::middleware::mode::AddrMode;
+use crate::operators::{is_illegal, OPERATORS};
+
+/// One assembled instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Inst {
+    pub opcode: u8,
+    operands: [u8; 2],
+    len: usize,
+}
+
+impl Inst {
+    /// Assembles `src`, which has to hold exactly one instruction.
+    pub const fn parse(src: &str) -> Self {
+        let src = src.as_bytes();
+        let (inst, end) = parse_inst(src, 0);
+        if skip_separators(src, end) != src.len() {
+            panic!("asm: expected a single instruction");
+        }
+        inst
+    }
+
+    pub const fn operand_len(&self) -> usize {
+        self.len
+    }
+
+    /// Operand byte `index`, in the order they are sent.
+    pub const fn operand(&self, index: usize) -> u8 {
+        self.operands[index]
+    }
+}
+
+/// Bytes `src` assembles to.
+pub const fn program_len(src: &str) -> usize {
+    let src = src.as_bytes();
+    let mut len = 0;
+    let mut i = skip_separators(src, 0);
+    while i < src.len() {
+        let (inst, end) = parse_inst(src, i);
+        len += 1 + inst.len;
+        i = skip_separators(src, end);
+    }
+    len
+}
+
+/// Assembles `src` into `N` bytes, which have to be [`program_len`] of it.
+pub const fn program<const N: usize>(src: &str) -> [u8; N] {
+    let src = src.as_bytes();
+    let mut bytes = [0x0u8; N];
+    let mut len = 0;
+    let mut i = skip_separators(src, 0);
+    while i < src.len() {
+        let (inst, end) = parse_inst(src, i);
+        if len + 1 + inst.len > N {
+            panic!("asm: program is longer than its buffer");
+        }
+        bytes[len] = inst.opcode;
+        let mut j = 0;
+        while j < inst.len {
+            bytes[len + 1 + j] = inst.operands[j];
+            j += 1;
+        }
+        len += 1 + inst.len;
+        i = skip_separators(src, end);
+    }
+    if len != N {
+        panic!("asm: program is shorter than its buffer");
+    }
+    bytes
+}
+
+/// `Command::inst` for one instruction written in assembler, checked at compile time.
+///
+/// `send_command(fc2, inst!("lda #$FF"))?;`
+#[macro_export]
+macro_rules! inst {
+    ($src:expr) => {{
+        const INST: $crate::asm::Inst = $crate::asm::Inst::parse($src);
+        const OPERANDS: &[u8] = match INST.operand_len() {
+            0 => &[],
+            1 => &[INST.operand(0)],
+            _ => &[INST.operand(0), INST.operand(1)],
+        };
+        const COMMAND: $crate::middleware::mode::command::Command<'static> =
+            match $crate::middleware::mode::command::Command::inst(INST.opcode, OPERANDS) {
+                Ok(command) => command,
+                Err(_) => panic!("asm: operand count does not match the opcode"),
+            };
+        COMMAND
+    }};
+}
+
+/// Bytes of a program written in assembler, checked at compile time.
+///
+/// `const PROGRAM: [u8; 5] = program!("lda #$FF; sta $0033");`
+#[macro_export]
+macro_rules! program {
+    ($src:expr) => {{
+        const LEN: usize = $crate::asm::program_len($src);
+        const BYTES: [u8; LEN] = $crate::asm::program::<LEN>($src);
+        BYTES
+    }};
+}
+
+/// Operand as written, before the opcode is known.
+#[derive(Clone, Copy)]
+struct Operand {
+    mode: AddrMode,
+    value: u16,
+    /// Written with more than two hex digits, so it stays absolute.
+    wide: bool,
+}
+
+const fn parse_inst(src: &[u8], start: usize) -> (Inst, usize) {
+    let i = skip_spaces(src, start);
+    if i + 3 > src.len() {
+        panic!("asm: expected a mnemonic");
+    }
+    let mnemonic = [
+        src[i].to_ascii_uppercase(),
+        src[i + 1].to_ascii_uppercase(),
+        src[i + 2].to_ascii_uppercase(),
+    ];
+    let i = i + 3;
+    if i < src.len() && src[i].is_ascii_alphanumeric() {
+        panic!("asm: unknown mnemonic");
+    }
+    let (operand, end) = parse_operand(src, skip_spaces(src, i));
+    let end = skip_spaces(src, end);
+    if end < src.len() && !is_separator(src[end]) {
+        panic!("asm: unexpected text after the operand");
+    }
+    (encode(mnemonic, operand), end)
+}
+
+const fn parse_operand(src: &[u8], i: usize) -> (Operand, usize) {
+    if i >= src.len() || is_separator(src[i]) {
+        return (operand(AddrMode::Impl, 0, false), i);
+    }
+    match src[i] {
+        b'#' => {
+            let (value, _, end) = parse_number(src, i + 1);
+            if value > 0xFF {
+                panic!("asm: immediate does not fit in a byte");
+            }
+            (operand(AddrMode::Imm, value, false), end)
+        }
+        b'*' => {
+            let negative = match byte_at(src, i + 1) {
+                b'+' => false,
+                b'-' => true,
+                _ => panic!("asm: expected `*+n` or `*-n`"),
+            };
+            let (value, _, end) = parse_number(src, i + 2);
+            // the offset counts from the next instruction, two bytes after the opcode.
+            let offset = if negative {
+                -(value as i32) - 2
+            } else {
+                value as i32 - 2
+            };
+            if offset < i8::MIN as i32 || offset > i8::MAX as i32 {
+                panic!("asm: branch is out of range");
+            }
+            (
+                operand(AddrMode::Rel, offset as i8 as u8 as u16, false),
+                end,
+            )
+        }
+        b'(' => {
+            let (value, wide, end) = parse_number(src, i + 1);
+            if expect(src, end, b",X)") {
+                (operand(AddrMode::IndX, value, wide), end + 3)
+            } else if expect(src, end, b"),Y") {
+                (operand(AddrMode::IndY, value, wide), end + 3)
+            } else if expect(src, end, b")") {
+                (operand(AddrMode::Ind, value, true), end + 1)
+            } else {
+                panic!("asm: expected `(n,X)`, `(n),Y` or `(n)`")
+            }
+        }
+        b'A' | b'a' if i + 1 >= src.len() || !src[i + 1].is_ascii_alphanumeric() => {
+            (operand(AddrMode::Acc, 0, false), i + 1)
+        }
+        _ => {
+            let (value, wide, end) = parse_number(src, i);
+            let zp = !wide && value <= 0xFF;
+            if expect(src, end, b",X") {
+                let mode = if zp { AddrMode::ZpX } else { AddrMode::AbsX };
+                (operand(mode, value, wide), end + 2)
+            } else if expect(src, end, b",Y") {
+                let mode = if zp { AddrMode::ZpY } else { AddrMode::AbsY };
+                (operand(mode, value, wide), end + 2)
+            } else {
+                let mode = if zp { AddrMode::Zp } else { AddrMode::Abs };
+                (operand(mode, value, wide), end)
+            }
+        }
+    }
+}
+
+const fn operand(mode: AddrMode, value: u16, wide: bool) -> Operand {
+    Operand { mode, value, wide }
+}
+
+/// Looks up the opcode of `mnemonic` with the addressing mode of `operand`, widening zero
+/// page to absolute and implied to accumulator when only those exist.
+const fn encode(mnemonic: [u8; 3], operand: Operand) -> Inst {
+    let mode = operand.mode;
+    let fallback = match mode {
+        AddrMode::Zp => Some(AddrMode::Abs),
+        AddrMode::ZpX => Some(AddrMode::AbsX),
+        AddrMode::ZpY => Some(AddrMode::AbsY),
+        AddrMode::Impl => Some(AddrMode::Acc),
+        _ => None,
+    };
+    if !is_mnemonic(mnemonic) {
+        panic!("asm: unknown mnemonic");
+    }
+    let opcode = match find(mnemonic, mode) {
+        Some(opcode) => opcode,
+        None => match fallback {
+            Some(fallback) => match find(mnemonic, fallback) {
+                Some(opcode) => opcode,
+                None => panic!("asm: addressing mode is not available for this mnemonic"),
+            },
+            None => panic!("asm: addressing mode is not available for this mnemonic"),
+        },
+    };
+    let len = OPERATORS[opcode as usize].operand_len();
+    if len == 1 && (operand.value > 0xFF || operand.wide) {
+        panic!("asm: operand does not fit in a byte");
+    }
+    let [low, high] = operand.value.to_le_bytes();
+    Inst {
+        opcode,
+        operands: [low, high],
+        len,
+    }
+}
+
+const fn is_mnemonic(mnemonic: [u8; 3]) -> bool {
+    let mut opcode = 0;
+    while opcode < 256 {
+        let name = OPERATORS[opcode].ope_kind.mnemonic().as_bytes();
+        if name[0] == mnemonic[0] && name[1] == mnemonic[1] && name[2] == mnemonic[2] {
+            return true;
+        }
+        opcode += 1;
+    }
+    false
+}
+
+/// First documented opcode of `mnemonic` in `mode`, or else the first illegal one.
+const fn find(mnemonic: [u8; 3], mode: AddrMode) -> Option<u8> {
+    let mut illegal = None;
+    let mut opcode = 0;
+    while opcode < 256 {
+        let operator = OPERATORS[opcode];
+        let name = operator.ope_kind.mnemonic().as_bytes();
+        // the `Nop` mode is the implied form of the illegal NOPs.
+        let operator_mode = match operator.addr_mode {
+            AddrMode::Nop => AddrMode::Impl,
+            addr_mode => addr_mode,
+        };
+        if name[0] == mnemonic[0]
+            && name[1] == mnemonic[1]
+            && name[2] == mnemonic[2]
+            && operator_mode as u8 == mode as u8
+        {
+            if !is_illegal(opcode as u8) {
+                return Some(opcode as u8);
+            }
+            if illegal.is_none() {
+                illegal = Some(opcode as u8);
+            }
+        }
+        opcode += 1;
+    }
+    illegal
+}
+
+/// Parses `$hex` or decimal at `i`. Returns the value, whether it was written wider than a
+/// byte, and where it ends.
+const fn parse_number(src: &[u8], i: usize) -> (u16, bool, usize) {
+    let (radix, start) = match byte_at(src, i) {
+        b'$' => (16, i + 1),
+        _ => (10, i),
+    };
+    let mut value: u32 = 0;
+    let mut end = start;
+    while end < src.len() {
+        let digit = match src[end] {
+            c @ b'0'..=b'9' => c - b'0',
+            c @ b'a'..=b'f' if radix == 16 => c - b'a' + 10,
+            c @ b'A'..=b'F' if radix == 16 => c - b'A' + 10,
+            _ => break,
+        };
+        value = value * radix + digit as u32;
+        if value > 0xFFFF {
+            panic!("asm: number does not fit in 16 bits");
+        }
+        end += 1;
+    }
+    if end == start {
+        panic!("asm: expected a number");
+    }
+    let wide = if radix == 16 {
+        end - start > 2
+    } else {
+        value > 0xFF
+    };
+    (value as u16, wide, end)
+}
+
+/// Whether `src` continues with `expected` at `i`, ignoring case.
+const fn expect(src: &[u8], i: usize, expected: &[u8]) -> bool {
+    if i + expected.len() > src.len() {
+        return false;
+    }
+    let mut j = 0;
+    while j < expected.len() {
+        if src[i + j].to_ascii_uppercase() != expected[j] {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+/// Byte at `i`, or 0 past the end.
+const fn byte_at(src: &[u8], i: usize) -> u8 {
+    if i < src.len() {
+        src[i]
+    } else {
+        0
+    }
+}
+
+const fn is_separator(c: u8) -> bool {
+    c == b';' || c == b'\n'
+}
+
+const fn skip_spaces(src: &[u8], mut i: usize) -> usize {
+    while i < src.len() && (src[i] == b' ' || src[i] == b'\t' || src[i] == b'\r') {
+        i += 1;
+    }
+    i
+}
+
+const fn skip_separators(src: &[u8], mut i: usize) -> usize {
+    while i < src.len() && (is_separator(src[i]) || src[i].is_ascii_whitespace()) {
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(src: &str) -> ([u8; 3], usize) {
+        let inst = Inst::parse(src);
+        let mut bytes = [inst.opcode, 0x0, 0x0];
+        for i in 0..inst.operand_len() {
+            bytes[1 + i] = inst.operand(i);
+        }
+        (bytes, 1 + inst.operand_len())
+    }
+
+    fn assert_assembles(src: &str, expected: &[u8]) {
+        let (bytes, len) = bytes(src);
+        assert_eq!(&bytes[..len], expected, "{}", src);
+    }
+
+    #[test]
+    fn addressing_modes() {
+        assert_assembles("lda #$FF", &[0xA9, 0xFF]);
+        assert_assembles("lda $33", &[0xA5, 0x33]);
+        assert_assembles("lda $33,X", &[0xB5, 0x33]);
+        assert_assembles("ldx $33,Y", &[0xB6, 0x33]);
+        assert_assembles("lda $C000", &[0xAD, 0x00, 0xC0]);
+        assert_assembles("lda $C000,X", &[0xBD, 0x00, 0xC0]);
+        assert_assembles("lda $C000,Y", &[0xB9, 0x00, 0xC0]);
+        assert_assembles("jmp ($0200)", &[0x6C, 0x00, 0x02]);
+        assert_assembles("lda ($33,X)", &[0xA1, 0x33]);
+        assert_assembles("lda ($33),Y", &[0xB1, 0x33]);
+        assert_assembles("asl A", &[0x0A]);
+        assert_assembles("inx", &[0xE8]);
+        assert_assembles("LDA #255", &[0xA9, 0xFF]);
+    }
+
+    #[test]
+    fn wide_operands_stay_absolute() {
+        assert_assembles("lda $0033", &[0xAD, 0x33, 0x00]);
+        assert_assembles("sta $0033,X", &[0x9D, 0x33, 0x00]);
+    }
+
+    #[test]
+    fn missing_zero_page_and_implied_forms_are_widened() {
+        assert_assembles("jmp $44", &[0x4C, 0x44, 0x00]);
+        assert_assembles("lda $33,Y", &[0xB9, 0x33, 0x00]);
+        assert_assembles("asl", &[0x0A]);
+    }
+
+    #[test]
+    fn documented_opcodes_are_picked_over_illegal_ones() {
+        // 0x1A, 0x3A, ... are illegal copies of NOP, and 0xEB of SBC #imm.
+        assert_assembles("nop", &[0xEA]);
+        assert_assembles("sbc #$10", &[0xE9, 0x10]);
+        assert_assembles("dop $44", &[0x04, 0x44]);
+        assert_assembles("lax $44", &[0xA7, 0x44]);
+    }
+
+    #[test]
+    fn branches_count_from_the_opcode() {
+        assert_assembles("bcs *+6", &[0xB0, 0x04]);
+        assert_assembles("bne *-2", &[0xD0, 0xFC]);
+        assert_assembles("beq *+2", &[0xF0, 0x00]);
+    }
+
+    #[test]
+    fn programs_are_split_by_separators() {
+        const PROGRAM: [u8; 7] = crate::program!("lda #$FF; sta $0033\n  inx\n\nasl A;");
+        assert_eq!(PROGRAM, [0xA9, 0xFF, 0x8D, 0x33, 0x00, 0xE8, 0x0A]);
+        assert_eq!(program_len("inx; iny"), 2);
+    }
+}
@@ -0,0 +1,34 @@
+/// Memory seen by the reference cpu.
+pub trait Bus {
+    fn read(&mut self, addr: u16) -> u8;
+    fn write(&mut self, addr: u16, data: u8);
+
+    /// Reads an opcode or operand byte at `addr`.
+    ///
+    /// In `OpeMode::Inst` the FPGA takes these bytes from usart instead of memory, so a bus
+    /// standing in for that mode overrides this method.
+    fn fetch(&mut self, addr: u16) -> u8 {
+        self.read(addr)
+    }
+}
+
+/// Plain 64KiB address space without any mirroring.
+pub struct FlatMemory<'a> {
+    memory: &'a mut [u8; 0x10000],
+}
+
+impl<'a> FlatMemory<'a> {
+    pub fn new(memory: &'a mut [u8; 0x10000]) -> Self {
+        Self { memory }
+    }
+}
+
+impl Bus for FlatMemory<'_> {
+    fn read(&mut self, addr: u16) -> u8 {
+        self.memory[addr as usize]
+    }
+
+    fn write(&mut self, addr: u16, data: u8) {
+        self.memory[addr as usize] = data;
+    }
+}
@@ -0,0 +1,736 @@
+pub mod bus;
+pub mod status;
+
+use crate::middleware::mode::AddrMode;
+use crate::operators::{OpeKind, Operator};
+use bus::Bus;
+pub use status::{FlagDiff, StatusFlags};
+
+pub const FLAG_C: u8 = 0b00000001;
+pub const FLAG_Z: u8 = 0b00000010;
+pub const FLAG_I: u8 = 0b00000100;
+pub const FLAG_D: u8 = 0b00001000;
+pub const FLAG_B: u8 = 0b00010000;
+pub const FLAG_U: u8 = 0b00100000;
+pub const FLAG_V: u8 = 0b01000000;
+pub const FLAG_N: u8 = 0b10000000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u8,
+    pub x: u8,
+    pub y: u8,
+    pub s: u8,
+    pub p: u8,
+    pub pc: u16,
+}
+
+impl Default for Registers {
+    /// Same values as the reset branch of `Debug` in debug.veryl.
+    fn default() -> Self {
+        Self {
+            a: 0,
+            x: 0,
+            y: 0,
+            s: 0xFD,
+            p: 0b00100100,
+            pc: 0,
+        }
+    }
+}
+
+impl Registers {
+    pub fn flags(&self) -> StatusFlags {
+        StatusFlags::from_bits(self.p)
+    }
+}
+
+/// Result of executing one instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub opcode: u8,
+    pub operator: Operator,
+    pub cycles: u8,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    None,
+    Imm(u8),
+    Addr { addr: u16, base: u16 },
+}
+
+/// Reference model of the 2A03 core. Decimal mode is ignored as on the famicom.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Cpu {
+    pub registers: Registers,
+    pub cycles: u64,
+}
+
+impl Cpu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_registers(registers: Registers) -> Self {
+        Self {
+            registers,
+            cycles: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Executes the instruction at pc and returns what was executed.
+    pub fn step<B: Bus>(&mut self, bus: &mut B) -> Step {
+        let opcode = self.fetch(bus);
+        let operator = Operator::decode(opcode);
+        let operand = self.operand(bus, operator.addr_mode);
+        let mut cycles = operator.cycles;
+        if let Operand::Addr { addr, base } = operand {
+            // branches take `Operand::Imm`, and `branch` counts their page cross.
+            if is_page_crossed(addr, base) {
+                cycles += operator.page_cross_cycles();
+            }
+        }
+        cycles += self.execute(bus, operator.ope_kind, operand);
+        self.cycles += cycles as u64;
+        Step {
+            opcode,
+            operator,
+            cycles,
+        }
+    }
+
+    fn fetch<B: Bus>(&mut self, bus: &mut B) -> u8 {
+        let data = bus.fetch(self.registers.pc);
+        self.registers.pc = self.registers.pc.wrapping_add(1);
+        data
+    }
+
+    fn fetch_word<B: Bus>(&mut self, bus: &mut B) -> u16 {
+        let low = self.fetch(bus);
+        let high = self.fetch(bus);
+        u16::from_le_bytes([low, high])
+    }
+
+    fn operand<B: Bus>(&mut self, bus: &mut B, addr_mode: AddrMode) -> Operand {
+        let r = self.registers;
+        match addr_mode {
+            AddrMode::Acc | AddrMode::Impl | AddrMode::Nop => Operand::None,
+            AddrMode::Imm | AddrMode::Rel => Operand::Imm(self.fetch(bus)),
+            AddrMode::Zp => direct(self.fetch(bus) as u16),
+            AddrMode::ZpX => direct(self.fetch(bus).wrapping_add(r.x) as u16),
+            AddrMode::ZpY => direct(self.fetch(bus).wrapping_add(r.y) as u16),
+            AddrMode::Abs => direct(self.fetch_word(bus)),
+            AddrMode::AbsX => indexed(self.fetch_word(bus), r.x),
+            AddrMode::AbsY => indexed(self.fetch_word(bus), r.y),
+            AddrMode::IndX => {
+                let pointer = self.fetch(bus).wrapping_add(r.x);
+                direct(read_zp_word(bus, pointer))
+            }
+            AddrMode::IndY => {
+                let pointer = self.fetch(bus);
+                indexed(read_zp_word(bus, pointer), r.y)
+            }
+            AddrMode::Ind => {
+                // the high byte is read without carrying into the next page.
+                let pointer = self.fetch_word(bus);
+                let low = bus.read(pointer);
+                let high = bus.read((pointer & 0xFF00) | (pointer.wrapping_add(1) & 0x00FF));
+                direct(u16::from_le_bytes([low, high]))
+            }
+        }
+    }
+
+    fn load<B: Bus>(&self, bus: &mut B, operand: Operand) -> u8 {
+        match operand {
+            Operand::None => self.registers.a,
+            Operand::Imm(data) => data,
+            Operand::Addr { addr, .. } => bus.read(addr),
+        }
+    }
+
+    /// Applies `f` to the accumulator or memory and returns the stored value.
+    fn modify<B: Bus>(&mut self, bus: &mut B, operand: Operand, f: fn(&mut Self, u8) -> u8) -> u8 {
+        match operand {
+            Operand::Addr { addr, .. } => {
+                let data = bus.read(addr);
+                let result = f(self, data);
+                bus.write(addr, result);
+                result
+            }
+            _ => {
+                let result = f(self, self.registers.a);
+                self.registers.a = result;
+                result
+            }
+        }
+    }
+
+    fn store<B: Bus>(&self, bus: &mut B, operand: Operand, data: u8) {
+        if let Operand::Addr { addr, .. } = operand {
+            bus.write(addr, data);
+        }
+    }
+
+    /// Runs the operation and returns extra cycles spent by taken branches.
+    fn execute<B: Bus>(&mut self, bus: &mut B, ope_kind: OpeKind, operand: Operand) -> u8 {
+        match ope_kind {
+            OpeKind::Adc => {
+                let data = self.load(bus, operand);
+                self.add_with_carry(data);
+            }
+            OpeKind::Sbc => {
+                let data = self.load(bus, operand);
+                self.add_with_carry(!data);
+            }
+            OpeKind::And => {
+                let data = self.load(bus, operand);
+                self.set_a(self.registers.a & data);
+            }
+            OpeKind::Ora => {
+                let data = self.load(bus, operand);
+                self.set_a(self.registers.a | data);
+            }
+            OpeKind::Eor => {
+                let data = self.load(bus, operand);
+                self.set_a(self.registers.a ^ data);
+            }
+            OpeKind::Asl => {
+                self.modify(bus, operand, Self::asl);
+            }
+            OpeKind::Lsr => {
+                self.modify(bus, operand, Self::lsr);
+            }
+            OpeKind::Rol => {
+                self.modify(bus, operand, Self::rol);
+            }
+            OpeKind::Ror => {
+                self.modify(bus, operand, Self::ror);
+            }
+            OpeKind::Bcc => return self.branch(operand, !self.flag(FLAG_C)),
+            OpeKind::Bcs => return self.branch(operand, self.flag(FLAG_C)),
+            OpeKind::Beq => return self.branch(operand, self.flag(FLAG_Z)),
+            OpeKind::Bne => return self.branch(operand, !self.flag(FLAG_Z)),
+            OpeKind::Bvc => return self.branch(operand, !self.flag(FLAG_V)),
+            OpeKind::Bvs => return self.branch(operand, self.flag(FLAG_V)),
+            OpeKind::Bpl => return self.branch(operand, !self.flag(FLAG_N)),
+            OpeKind::Bmi => return self.branch(operand, self.flag(FLAG_N)),
+            OpeKind::Bit => {
+                let data = self.load(bus, operand);
+                self.set_flag(FLAG_Z, self.registers.a & data == 0);
+                self.set_flag(FLAG_N, data & FLAG_N != 0);
+                self.set_flag(FLAG_V, data & FLAG_V != 0);
+            }
+            OpeKind::Jmp => {
+                if let Operand::Addr { addr, .. } = operand {
+                    self.registers.pc = addr;
+                }
+            }
+            OpeKind::Jsr => {
+                if let Operand::Addr { addr, .. } = operand {
+                    self.push_word(bus, self.registers.pc.wrapping_sub(1));
+                    self.registers.pc = addr;
+                }
+            }
+            OpeKind::Rts => {
+                self.registers.pc = self.pull_word(bus).wrapping_add(1);
+            }
+            OpeKind::Brk => {
+                // like the FPGA, B is kept in p. The padding byte is skipped without being read.
+                self.set_flag(FLAG_B, true);
+                self.push_word(bus, self.registers.pc.wrapping_add(1));
+                self.push(bus, self.registers.p);
+                self.set_flag(FLAG_I, true);
+                let low = bus.read(0xFFFE);
+                let high = bus.read(0xFFFF);
+                self.registers.pc = u16::from_le_bytes([low, high]);
+            }
+            OpeKind::Rti => {
+                // Unlike PLP, the FPGA loads P as pulled, B included; see the
+                // Rti arm of ope_read_and_store_to_register.veryl.
+                self.registers.p = self.pull(bus);
+                self.registers.pc = self.pull_word(bus);
+            }
+            OpeKind::Cmp => {
+                let data = self.load(bus, operand);
+                self.compare(self.registers.a, data);
+            }
+            OpeKind::Cpx => {
+                let data = self.load(bus, operand);
+                self.compare(self.registers.x, data);
+            }
+            OpeKind::Cpy => {
+                let data = self.load(bus, operand);
+                self.compare(self.registers.y, data);
+            }
+            OpeKind::Inc => {
+                self.modify(bus, operand, Self::inc);
+            }
+            OpeKind::Dec => {
+                self.modify(bus, operand, Self::dec);
+            }
+            OpeKind::Inx => {
+                self.registers.x = self.inc(self.registers.x);
+            }
+            OpeKind::Dex => {
+                self.registers.x = self.dec(self.registers.x);
+            }
+            OpeKind::Iny => {
+                self.registers.y = self.inc(self.registers.y);
+            }
+            OpeKind::Dey => {
+                self.registers.y = self.dec(self.registers.y);
+            }
+            OpeKind::Clc => self.set_flag(FLAG_C, false),
+            OpeKind::Sec => self.set_flag(FLAG_C, true),
+            OpeKind::Cli => self.set_flag(FLAG_I, false),
+            OpeKind::Sei => self.set_flag(FLAG_I, true),
+            OpeKind::Cld => self.set_flag(FLAG_D, false),
+            OpeKind::Sed => self.set_flag(FLAG_D, true),
+            OpeKind::Clv => self.set_flag(FLAG_V, false),
+            OpeKind::Lda => {
+                let data = self.load(bus, operand);
+                self.set_a(data);
+            }
+            OpeKind::Ldx => {
+                let data = self.load(bus, operand);
+                self.set_x(data);
+            }
+            OpeKind::Ldy => {
+                let data = self.load(bus, operand);
+                self.set_y(data);
+            }
+            OpeKind::Sta => self.store(bus, operand, self.registers.a),
+            OpeKind::Stx => self.store(bus, operand, self.registers.x),
+            OpeKind::Sty => self.store(bus, operand, self.registers.y),
+            OpeKind::Tax => self.set_x(self.registers.a),
+            OpeKind::Txa => self.set_a(self.registers.x),
+            OpeKind::Tay => self.set_y(self.registers.a),
+            OpeKind::Tya => self.set_a(self.registers.y),
+            OpeKind::Tsx => self.set_x(self.registers.s),
+            OpeKind::Txs => self.registers.s = self.registers.x,
+            OpeKind::Pha => self.push(bus, self.registers.a),
+            OpeKind::Pla => {
+                let data = self.pull(bus);
+                self.set_a(data);
+            }
+            OpeKind::Php => self.push(bus, self.registers.p),
+            OpeKind::Plp => {
+                let p = self.pull(bus);
+                self.set_p(p);
+            }
+            // the operand of illegal nops is decoded but never read, as on the FPGA.
+            OpeKind::Nop | OpeKind::Dop | OpeKind::Top => {}
+            OpeKind::Kil => {
+                // the cpu jams, so stay on the same opcode.
+                self.registers.pc = self.registers.pc.wrapping_sub(1);
+            }
+            OpeKind::Slo => {
+                let result = self.modify(bus, operand, Self::asl);
+                self.set_a(self.registers.a | result);
+            }
+            OpeKind::Rla => {
+                let result = self.modify(bus, operand, Self::rol);
+                self.set_a(self.registers.a & result);
+            }
+            OpeKind::Sre => {
+                let result = self.modify(bus, operand, Self::lsr);
+                self.set_a(self.registers.a ^ result);
+            }
+            OpeKind::Rra => {
+                let result = self.modify(bus, operand, Self::ror);
+                self.add_with_carry(result);
+            }
+            OpeKind::Dcp => {
+                let result = self.modify(bus, operand, |_, data| data.wrapping_sub(1));
+                self.compare(self.registers.a, result);
+            }
+            OpeKind::Isb => {
+                let result = self.modify(bus, operand, |_, data| data.wrapping_add(1));
+                self.add_with_carry(!result);
+            }
+            OpeKind::Sax => self.store(bus, operand, self.registers.a & self.registers.x),
+            OpeKind::Lax => {
+                let data = self.load(bus, operand);
+                self.registers.x = data;
+                self.set_a(data);
+            }
+            OpeKind::Aac => {
+                let data = self.load(bus, operand);
+                self.set_a(self.registers.a & data);
+                self.set_flag(FLAG_C, self.flag(FLAG_N));
+            }
+            OpeKind::Asr => {
+                let data = self.load(bus, operand);
+                let result = self.lsr(self.registers.a & data);
+                self.registers.a = result;
+            }
+            OpeKind::Arr => {
+                let data = self.load(bus, operand);
+                let result = self.ror(self.registers.a & data);
+                self.set_a(result);
+                self.set_flag(FLAG_C, result & 0b01000000 != 0);
+                self.set_flag(FLAG_V, ((result >> 6) ^ (result >> 5)) & 1 != 0);
+            }
+            OpeKind::Xaa => {
+                let data = self.load(bus, operand);
+                self.set_a((self.registers.a | 0xEE) & self.registers.x & data);
+            }
+            OpeKind::Atx | OpeKind::Lxa => {
+                let data = self.load(bus, operand);
+                self.set_a((self.registers.a | 0xEE) & data);
+                self.registers.x = self.registers.a;
+            }
+            OpeKind::Axs => {
+                let data = self.load(bus, operand);
+                let masked = self.registers.a & self.registers.x;
+                self.set_flag(FLAG_C, masked >= data);
+                self.set_x(masked.wrapping_sub(data));
+            }
+            OpeKind::Axa => {
+                let data = self.registers.a & self.registers.x & high_plus_one(operand);
+                self.store(bus, operand, data);
+            }
+            OpeKind::Xas => {
+                self.registers.s = self.registers.a & self.registers.x;
+                self.store(bus, operand, self.registers.s & high_plus_one(operand));
+            }
+            OpeKind::Sxa => self.store(bus, operand, self.registers.x & high_plus_one(operand)),
+            OpeKind::Sya => self.store(bus, operand, self.registers.y & high_plus_one(operand)),
+            OpeKind::Las => {
+                let data = self.load(bus, operand) & self.registers.s;
+                self.registers.s = data;
+                self.registers.x = data;
+                self.set_a(data);
+            }
+        }
+        0
+    }
+
+    fn branch(&mut self, operand: Operand, condition: bool) -> u8 {
+        let Operand::Imm(offset) = operand else {
+            return 0;
+        };
+        if !condition {
+            return 0;
+        }
+        let pc = self.registers.pc;
+        self.registers.pc = pc.wrapping_add(offset as i8 as u16);
+        if is_page_crossed(self.registers.pc, pc) {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn add_with_carry(&mut self, data: u8) {
+        let a = self.registers.a;
+        let sum = a as u16 + data as u16 + self.flag(FLAG_C) as u16;
+        let result = sum as u8;
+        self.set_flag(FLAG_C, sum > 0xFF);
+        self.set_flag(FLAG_V, (!(a ^ data) & (a ^ result) & 0x80) != 0);
+        self.set_a(result);
+    }
+
+    fn compare(&mut self, register: u8, data: u8) {
+        self.set_flag(FLAG_C, register >= data);
+        self.set_nz(register.wrapping_sub(data));
+    }
+
+    fn asl(&mut self, data: u8) -> u8 {
+        self.set_flag(FLAG_C, data & 0x80 != 0);
+        let result = data << 1;
+        self.set_nz(result);
+        result
+    }
+
+    fn lsr(&mut self, data: u8) -> u8 {
+        self.set_flag(FLAG_C, data & 0x01 != 0);
+        let result = data >> 1;
+        self.set_nz(result);
+        result
+    }
+
+    fn rol(&mut self, data: u8) -> u8 {
+        let result = (data << 1) | self.flag(FLAG_C) as u8;
+        self.set_flag(FLAG_C, data & 0x80 != 0);
+        self.set_nz(result);
+        result
+    }
+
+    fn ror(&mut self, data: u8) -> u8 {
+        let result = (data >> 1) | ((self.flag(FLAG_C) as u8) << 7);
+        self.set_flag(FLAG_C, data & 0x01 != 0);
+        self.set_nz(result);
+        result
+    }
+
+    fn inc(&mut self, data: u8) -> u8 {
+        let result = data.wrapping_add(1);
+        self.set_nz(result);
+        result
+    }
+
+    fn dec(&mut self, data: u8) -> u8 {
+        let result = data.wrapping_sub(1);
+        self.set_nz(result);
+        result
+    }
+
+    fn push<B: Bus>(&mut self, bus: &mut B, data: u8) {
+        bus.write(0x0100 | self.registers.s as u16, data);
+        self.registers.s = self.registers.s.wrapping_sub(1);
+    }
+
+    fn push_word<B: Bus>(&mut self, bus: &mut B, data: u16) {
+        let [low, high] = data.to_le_bytes();
+        self.push(bus, high);
+        self.push(bus, low);
+    }
+
+    fn pull<B: Bus>(&mut self, bus: &mut B) -> u8 {
+        self.registers.s = self.registers.s.wrapping_add(1);
+        bus.read(0x0100 | self.registers.s as u16)
+    }
+
+    fn pull_word<B: Bus>(&mut self, bus: &mut B) -> u16 {
+        let low = self.pull(bus);
+        let high = self.pull(bus);
+        u16::from_le_bytes([low, high])
+    }
+
+    fn set_a(&mut self, data: u8) {
+        self.registers.a = data;
+        self.set_nz(data);
+    }
+
+    fn set_x(&mut self, data: u8) {
+        self.registers.x = data;
+        self.set_nz(data);
+    }
+
+    fn set_y(&mut self, data: u8) {
+        self.registers.y = data;
+        self.set_nz(data);
+    }
+
+    /// PLP drops B but, unlike the NMOS core, leaves U as pulled.
+    fn set_p(&mut self, p: u8) {
+        self.registers.p = p & !FLAG_B;
+    }
+
+    fn flag(&self, flag: u8) -> bool {
+        self.registers.p & flag != 0
+    }
+
+    fn set_flag(&mut self, flag: u8, value: bool) {
+        if value {
+            self.registers.p |= flag;
+        } else {
+            self.registers.p &= !flag;
+        }
+    }
+
+    fn set_nz(&mut self, data: u8) {
+        self.set_flag(FLAG_Z, data == 0);
+        self.set_flag(FLAG_N, data & 0x80 != 0);
+    }
+}
+
+fn direct(addr: u16) -> Operand {
+    Operand::Addr { addr, base: addr }
+}
+
+fn indexed(base: u16, index: u8) -> Operand {
+    Operand::Addr {
+        addr: base.wrapping_add(index as u16),
+        base,
+    }
+}
+
+fn read_zp_word<B: Bus>(bus: &mut B, pointer: u8) -> u16 {
+    let low = bus.read(pointer as u16);
+    let high = bus.read(pointer.wrapping_add(1) as u16);
+    u16::from_le_bytes([low, high])
+}
+
+fn is_page_crossed(l: u16, r: u16) -> bool {
+    l & 0xFF00 != r & 0xFF00
+}
+
+fn high_plus_one(operand: Operand) -> u8 {
+    match operand {
+        Operand::Addr { base, .. } => ((base >> 8) as u8).wrapping_add(1),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bus::FlatMemory;
+    use super::*;
+
+    const START: u16 = 0x0600;
+
+    /// Runs the first instruction of `program`, placed at $0600, from `registers`.
+    fn step(registers: Registers, memory: &mut [u8; 0x10000], program: &[u8]) -> (Cpu, Step) {
+        let start = START as usize;
+        memory[start..start + program.len()].copy_from_slice(program);
+        let mut cpu = Cpu::with_registers(Registers {
+            pc: START,
+            ..registers
+        });
+        let step = cpu.step(&mut FlatMemory::new(memory));
+        (cpu, step)
+    }
+
+    fn run(registers: Registers, program: &[u8]) -> Cpu {
+        step(registers, &mut [0x0u8; 0x10000], program).0
+    }
+
+    fn with_a(a: u8, p: u8) -> Registers {
+        Registers {
+            a,
+            p,
+            ..Registers::default()
+        }
+    }
+
+    #[test]
+    fn loads_set_n_and_z() {
+        let cpu = run(Registers::default(), &[0xA9, 0x00]);
+        assert_eq!(cpu.registers.p, FLAG_U | FLAG_I | FLAG_Z);
+        let cpu = run(Registers::default(), &[0xA9, 0x80]);
+        assert_eq!(cpu.registers.p, FLAG_U | FLAG_I | FLAG_N);
+        assert_eq!(cpu.registers.pc, START + 2);
+    }
+
+    #[test]
+    fn flag_instructions() {
+        let p = FLAG_U;
+        assert_eq!(run(with_a(0, p), &[0x38]).registers.p, p | FLAG_C);
+        assert_eq!(run(with_a(0, p), &[0x78]).registers.p, p | FLAG_I);
+        assert_eq!(run(with_a(0, p), &[0xF8]).registers.p, p | FLAG_D);
+        assert_eq!(run(with_a(0, 0xFF), &[0x18]).registers.p, !FLAG_C);
+        assert_eq!(run(with_a(0, 0xFF), &[0xB8]).registers.p, !FLAG_V);
+    }
+
+    #[test]
+    fn plp_drops_b_and_keeps_u_as_pulled() {
+        let mut memory = [0x0u8; 0x10000];
+        memory[0x01FE] = 0xFF;
+        let (cpu, _) = step(Registers::default(), &mut memory, &[0x28]);
+        assert_eq!(cpu.registers.p, !FLAG_B);
+        memory[0x01FE] = 0x00;
+        let (cpu, _) = step(Registers::default(), &mut memory, &[0x28]);
+        assert_eq!(cpu.registers.p, 0x00);
+    }
+
+    #[test]
+    fn rti_keeps_b_as_pulled() {
+        let mut memory = [0x0u8; 0x10000];
+        // the stack pointer wraps within page one, so pch comes from $0100.
+        memory[0x01FE..=0x01FF].copy_from_slice(&[0xFF, 0x34]);
+        memory[0x0100] = 0x12;
+        let (cpu, _) = step(Registers::default(), &mut memory, &[0x40]);
+        assert_eq!((cpu.registers.p, cpu.registers.pc), (0xFF, 0x1234));
+    }
+
+    #[test]
+    fn ldy_and_tay_set_n_and_z() {
+        let cpu = run(Registers::default(), &[0xA0, 0x80]);
+        assert_eq!(
+            (cpu.registers.y, cpu.registers.p),
+            (0x80, FLAG_U | FLAG_I | FLAG_N)
+        );
+        let cpu = run(with_a(0x00, 0), &[0xA8]);
+        assert_eq!((cpu.registers.y, cpu.registers.p), (0x00, FLAG_Z));
+    }
+
+    #[test]
+    fn adc_ignores_decimal_mode() {
+        let cpu = run(with_a(0x09, FLAG_D), &[0x69, 0x01]);
+        assert_eq!(cpu.registers.a, 0x0A);
+        let cpu = run(with_a(0x7F, 0), &[0x69, 0x01]);
+        assert_eq!((cpu.registers.a, cpu.registers.p), (0x80, FLAG_V | FLAG_N));
+        let cpu = run(with_a(0xFF, 0), &[0x69, 0x01]);
+        assert_eq!((cpu.registers.a, cpu.registers.p), (0x00, FLAG_C | FLAG_Z));
+        let cpu = run(with_a(0x01, FLAG_C), &[0x69, 0x01]);
+        assert_eq!((cpu.registers.a, cpu.registers.p), (0x03, 0));
+    }
+
+    #[test]
+    fn sbc_ignores_decimal_mode() {
+        let cpu = run(with_a(0x10, FLAG_D | FLAG_C), &[0xE9, 0x01]);
+        assert_eq!(cpu.registers.a, 0x0F);
+        let cpu = run(with_a(0x00, FLAG_C), &[0xE9, 0x01]);
+        assert_eq!((cpu.registers.a, cpu.registers.p), (0xFF, FLAG_N));
+        let cpu = run(with_a(0x80, FLAG_C), &[0xE9, 0x01]);
+        assert_eq!((cpu.registers.a, cpu.registers.p), (0x7F, FLAG_C | FLAG_V));
+        // without carry one more is borrowed.
+        let cpu = run(with_a(0x05, 0), &[0xE9, 0x01]);
+        assert_eq!((cpu.registers.a, cpu.registers.p), (0x03, FLAG_C));
+    }
+
+    #[test]
+    fn page_cross_cycles() {
+        let x = |x| Registers {
+            x,
+            ..Registers::default()
+        };
+        let cycles =
+            |registers, program: &[u8]| step(registers, &mut [0x0u8; 0x10000], program).1.cycles;
+        // LDA $10FF,X
+        assert_eq!(cycles(x(0), &[0xBD, 0xFF, 0x10]), 4);
+        assert_eq!(cycles(x(1), &[0xBD, 0xFF, 0x10]), 5);
+        // stores have no penalty, STA $10FF,X takes what debug.veryl gives it either way.
+        assert_eq!(cycles(x(0), &[0x9D, 0xFF, 0x10]), 4);
+        assert_eq!(cycles(x(1), &[0x9D, 0xFF, 0x10]), 4);
+        // BNE, not taken, taken on the same page and taken onto the previous one.
+        let p = |p| with_a(0, p);
+        assert_eq!(cycles(p(FLAG_Z), &[0xD0, 0x10]), 2);
+        assert_eq!(cycles(p(0), &[0xD0, 0x10]), 3);
+        assert_eq!(cycles(p(0), &[0xD0, 0x80]), 4);
+    }
+
+    #[test]
+    fn illegal_opcodes() {
+        let mut memory = [0x0u8; 0x10000];
+        memory[0x0010] = 0x5A;
+        // LAX $10
+        let (cpu, _) = step(Registers::default(), &mut memory, &[0xA7, 0x10]);
+        assert_eq!((cpu.registers.a, cpu.registers.x), (0x5A, 0x5A));
+        // SAX $20
+        let registers = Registers {
+            a: 0xF0,
+            x: 0x3C,
+            ..Registers::default()
+        };
+        step(registers, &mut memory, &[0x87, 0x20]);
+        assert_eq!(memory[0x0020], 0x30);
+        // DCP $10 decrements and compares.
+        let (cpu, _) = step(with_a(0x59, 0), &mut memory, &[0xC7, 0x10]);
+        assert_eq!((memory[0x0010], cpu.registers.p), (0x59, FLAG_C | FLAG_Z));
+        // ISB $10 increments and subtracts.
+        let (cpu, _) = step(with_a(0x60, FLAG_C), &mut memory, &[0xE7, 0x10]);
+        assert_eq!((memory[0x0010], cpu.registers.a), (0x5A, 0x06));
+        // DOP #$FF skips its operand and TOP $1234 does not touch memory.
+        assert_eq!(
+            run(Registers::default(), &[0x80, 0xFF]).registers.pc,
+            START + 2
+        );
+        let (cpu, _) = step(Registers::default(), &mut memory, &[0x0C, 0x34, 0x12]);
+        assert_eq!((cpu.registers.pc, cpu.registers.a), (START + 3, 0));
+        // KIL jams on its own opcode.
+        assert_eq!(run(Registers::default(), &[0x02]).registers.pc, START);
+        // 0xEB is another SBC #imm.
+        assert_eq!(
+            run(with_a(0x10, FLAG_C), &[0xEB, 0x01]).registers,
+            run(with_a(0x10, FLAG_C), &[0xE9, 0x01]).registers
+        );
+    }
+}
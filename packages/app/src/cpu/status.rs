@@ -0,0 +1,295 @@
+use super::{FLAG_B, FLAG_C, FLAG_D, FLAG_I, FLAG_N, FLAG_U, FLAG_V, FLAG_Z};
+use core::fmt;
+use core::ops::BitOr;
+use core::str::FromStr;
+
+/// Letters of the flags from bit 7 down to bit 0.
+const LETTERS: [u8; 8] = *b"NVUBDIZC";
+
+/// Value of the P register with its bits named.
+///
+/// Written as the letters `NVUBDIZC`, upper case for a set flag and lower case for a clear
+/// one, so `0b10100100` is `NvUbdIzc`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StatusFlags(u8);
+
+impl StatusFlags {
+    pub const C: Self = Self(FLAG_C);
+    pub const Z: Self = Self(FLAG_Z);
+    pub const I: Self = Self(FLAG_I);
+    pub const D: Self = Self(FLAG_D);
+    pub const B: Self = Self(FLAG_B);
+    pub const U: Self = Self(FLAG_U);
+    pub const V: Self = Self(FLAG_V);
+    pub const N: Self = Self(FLAG_N);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Whether every flag of `flags` is set.
+    pub const fn contains(self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    pub const fn with(self, flags: Self) -> Self {
+        Self(self.0 | flags.0)
+    }
+
+    pub const fn without(self, flags: Self) -> Self {
+        Self(self.0 & !flags.0)
+    }
+
+    /// Sets or clears `flags` depending on `value`.
+    pub const fn set(self, flags: Self, value: bool) -> Self {
+        if value {
+            self.with(flags)
+        } else {
+            self.without(flags)
+        }
+    }
+
+    /// Flags that differ when `self` was expected and `actual` came back.
+    pub const fn diff(self, actual: Self) -> FlagDiff {
+        FlagDiff {
+            expected: self,
+            actual,
+        }
+    }
+
+    /// `NvUbdIzc` as ASCII.
+    fn letters(self) -> [u8; 8] {
+        let mut letters = LETTERS;
+        for (bit, letter) in (0..8).rev().zip(letters.iter_mut()) {
+            if self.0 & (1 << bit) == 0 {
+                letter.make_ascii_lowercase();
+            }
+        }
+        letters
+    }
+}
+
+impl BitOr for StatusFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.with(rhs)
+    }
+}
+
+impl From<u8> for StatusFlags {
+    fn from(bits: u8) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<StatusFlags> for u8 {
+    fn from(flags: StatusFlags) -> Self {
+        flags.0
+    }
+}
+
+/// Text that is neither `NvUbdIzc` nor a number such as `$A4`, `0xA4` or `0b10100100`.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFlagsError {
+    /// Character at this position is not the flag letter expected there.
+    Letter(usize),
+    Number,
+}
+
+impl FromStr for StatusFlags {
+    type Err = ParseFlagsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |digits: &str, radix| {
+            u8::from_str_radix(digits, radix).map_err(|_| ParseFlagsError::Number)
+        };
+        if let Some(hex) = s.strip_prefix('$').or_else(|| s.strip_prefix("0x")) {
+            return number(hex, 16).map(Self);
+        }
+        if let Some(binary) = s.strip_prefix("0b") {
+            return number(binary, 2).map(Self);
+        }
+        if s.len() != LETTERS.len() {
+            return Err(ParseFlagsError::Letter(s.len().min(LETTERS.len())));
+        }
+        let mut bits = 0;
+        for (i, (c, letter)) in s.bytes().zip(LETTERS).enumerate() {
+            bits <<= 1;
+            if c == letter {
+                bits |= 1;
+            } else if c != letter.to_ascii_lowercase() {
+                return Err(ParseFlagsError::Letter(i));
+            }
+        }
+        Ok(Self(bits))
+    }
+}
+
+impl fmt::Display for StatusFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letters = self.letters();
+        // only ASCII letters go in.
+        f.write_str(core::str::from_utf8(&letters).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for StatusFlags {
+    fn format(&self, f: defmt::Formatter) {
+        let letters = self.letters();
+        defmt::write!(f, "{=str}", core::str::from_utf8(&letters).unwrap_or("?"))
+    }
+}
+
+/// Flags of an expected and an actual P register, written as
+/// `expected N set, got N clear; expected Z clear, got Z set` for the ones that differ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FlagDiff {
+    pub expected: StatusFlags,
+    pub actual: StatusFlags,
+}
+
+impl FlagDiff {
+    pub fn is_empty(&self) -> bool {
+        self.expected == self.actual
+    }
+
+    /// Letter of each differing flag from N down to C and whether it was expected set.
+    pub fn iter(&self) -> impl Iterator<Item = (char, bool)> {
+        let differs = self.expected.0 ^ self.actual.0;
+        let expected = self.expected.0;
+        (0..8)
+            .rev()
+            .zip(LETTERS)
+            .filter(move |(bit, _)| differs & (1 << bit) != 0)
+            .map(move |(bit, letter)| (letter as char, expected & (1 << bit) != 0))
+    }
+}
+
+fn state(set: bool) -> &'static str {
+    if set {
+        "set"
+    } else {
+        "clear"
+    }
+}
+
+impl fmt::Display for FlagDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (letter, expected)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(
+                f,
+                "expected {} {}, got {} {}",
+                letter,
+                state(expected),
+                letter,
+                state(!expected)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for FlagDiff {
+    fn format(&self, f: defmt::Formatter) {
+        for (i, (letter, expected)) in self.iter().enumerate() {
+            if i > 0 {
+                defmt::write!(f, "; ");
+            }
+            defmt::write!(
+                f,
+                "expected {=char} {=str}, got {=char} {=str}",
+                letter,
+                state(expected),
+                letter,
+                state(!expected)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn parses_letters() {
+        assert_eq!("NvUbdIzc".parse(), Ok(StatusFlags::from_bits(0b10100100)));
+        assert_eq!("nvubdizc".parse(), Ok(StatusFlags::empty()));
+        assert_eq!("NVUBDIZC".parse(), Ok(StatusFlags::from_bits(0xFF)));
+        assert_eq!(
+            "NvUbdIzc".parse::<StatusFlags>().unwrap(),
+            StatusFlags::N | StatusFlags::U | StatusFlags::I
+        );
+    }
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!("$A4".parse(), Ok(StatusFlags::from_bits(0xA4)));
+        assert_eq!("$a4".parse(), Ok(StatusFlags::from_bits(0xA4)));
+        assert_eq!("0x24".parse(), Ok(StatusFlags::from_bits(0x24)));
+        assert_eq!("0b10100100".parse(), Ok(StatusFlags::from_bits(0xA4)));
+        assert_eq!("0b1".parse(), Ok(StatusFlags::C));
+    }
+
+    #[test]
+    fn rejects_invalid_text() {
+        let parse = |s: &str| s.parse::<StatusFlags>();
+        // letters out of place, unknown or of the wrong count.
+        assert_eq!(parse("vNUbdIzc"), Err(ParseFlagsError::Letter(0)));
+        assert_eq!(parse("NvUbdIzx"), Err(ParseFlagsError::Letter(7)));
+        assert_eq!(parse("NvUbdIz"), Err(ParseFlagsError::Letter(7)));
+        assert_eq!(parse("NvUbdIzcC"), Err(ParseFlagsError::Letter(8)));
+        assert_eq!(parse(""), Err(ParseFlagsError::Letter(0)));
+        // numbers that are not a byte.
+        assert_eq!(parse("$"), Err(ParseFlagsError::Number));
+        assert_eq!(parse("$1A4"), Err(ParseFlagsError::Number));
+        assert_eq!(parse("0xG0"), Err(ParseFlagsError::Number));
+        assert_eq!(parse("0b102"), Err(ParseFlagsError::Number));
+        assert_eq!(parse("0b101001001"), Err(ParseFlagsError::Number));
+    }
+
+    #[test]
+    fn displays_letters() {
+        assert_eq!(StatusFlags::from_bits(0b10100100).to_string(), "NvUbdIzc");
+        assert_eq!(StatusFlags::empty().to_string(), "nvubdizc");
+        let flags = StatusFlags::from_bits(0x65);
+        assert_eq!(flags.to_string().parse(), Ok(flags));
+    }
+
+    #[test]
+    fn diff_names_each_differing_flag() {
+        let diff = |expected: u8, actual: u8| {
+            StatusFlags::from_bits(expected).diff(StatusFlags::from_bits(actual))
+        };
+        assert_eq!(diff(0xA4, 0x24).to_string(), "expected N set, got N clear");
+        assert_eq!(diff(0x24, 0x26).to_string(), "expected Z clear, got Z set");
+        assert_eq!(
+            diff(0b10000001, 0b01000000).to_string(),
+            "expected N set, got N clear; expected V clear, got V set; expected C set, got C clear"
+        );
+        assert!(diff(0x24, 0x24).is_empty());
+        assert_eq!(diff(0x24, 0x24).to_string(), "");
+        assert_eq!(
+            diff(0x02, 0x00).iter().collect::<std::vec::Vec<_>>(),
+            [('Z', true)]
+        );
+    }
+}
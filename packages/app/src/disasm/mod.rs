@@ -0,0 +1,370 @@
+//! Renders instruction bytes as assembler text, such as `STA $0033`, and cpu states as
+//! nestest-style trace lines.
+
+use crate::cpu::Registers;
+use crate::middleware::mode::AddrMode;
+use crate::operators::{is_illegal, Operator, OPERATORS};
+use core::fmt;
+
+/// Column of `A:` in a trace line, as in the Nintendulator nestest.log.
+const REGISTERS_COLUMN: usize = 48;
+/// Column of the mnemonic in a trace line, or of the `*` marking an illegal opcode.
+const MNEMONIC_COLUMN: usize = 15;
+
+/// Instruction decoded from its bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: u8,
+    pub operator: Operator,
+    operands: [u8; 2],
+    /// Address of the opcode. Relative branches print their target when it is known.
+    pub pc: Option<u16>,
+}
+
+impl Instruction {
+    /// Decodes the instruction at the head of `bytes`. Returns `None` when `bytes` is shorter
+    /// than the addressing mode needs.
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let (&opcode, rest) = bytes.split_first()?;
+        let operator = OPERATORS[opcode as usize];
+        let operands = rest.get(..operator.operand_len())?;
+        let mut buf = [0x0u8; 2];
+        buf[..operands.len()].copy_from_slice(operands);
+        Some(Self {
+            opcode,
+            operator,
+            operands: buf,
+            pc: None,
+        })
+    }
+
+    /// Same instruction placed at `pc`.
+    pub fn at(self, pc: u16) -> Self {
+        Self {
+            pc: Some(pc),
+            ..self
+        }
+    }
+
+    pub fn operands(&self) -> &[u8] {
+        &self.operands[..self.operator.operand_len()]
+    }
+
+    /// Operand read as a little endian address, or the single operand byte.
+    pub fn operand(&self) -> u16 {
+        u16::from_le_bytes(self.operands)
+    }
+
+    /// Where a relative branch goes when taken, if the instruction is placed.
+    pub fn branch_target(&self) -> Option<u16> {
+        match self.operator.addr_mode {
+            AddrMode::Rel => {
+                let next = self.pc?.wrapping_add(2);
+                Some(next.wrapping_add(self.operands[0] as i8 as u16))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_illegal(&self) -> bool {
+        is_illegal(self.opcode)
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = self.operator.ope_kind.mnemonic();
+        let [low, _] = self.operands;
+        let addr = self.operand();
+        match self.operator.addr_mode {
+            AddrMode::Impl | AddrMode::Nop => write!(f, "{}", mnemonic),
+            AddrMode::Acc => write!(f, "{} A", mnemonic),
+            AddrMode::Imm => write!(f, "{} #${:02X}", mnemonic, low),
+            AddrMode::Zp => write!(f, "{} ${:02X}", mnemonic, low),
+            AddrMode::ZpX => write!(f, "{} ${:02X},X", mnemonic, low),
+            AddrMode::ZpY => write!(f, "{} ${:02X},Y", mnemonic, low),
+            AddrMode::Abs => write!(f, "{} ${:04X}", mnemonic, addr),
+            AddrMode::AbsX => write!(f, "{} ${:04X},X", mnemonic, addr),
+            AddrMode::AbsY => write!(f, "{} ${:04X},Y", mnemonic, addr),
+            AddrMode::Ind => write!(f, "{} (${:04X})", mnemonic, addr),
+            AddrMode::IndX => write!(f, "{} (${:02X},X)", mnemonic, low),
+            AddrMode::IndY => write!(f, "{} (${:02X}),Y", mnemonic, low),
+            AddrMode::Rel => match self.branch_target() {
+                Some(target) => write!(f, "{} ${:04X}", mnemonic, target),
+                // relative to the opcode, as assemblers write `*`.
+                None => write!(f, "{} *{:+}", mnemonic, low as i8 as i16 + 2),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Instruction {
+    fn format(&self, f: defmt::Formatter) {
+        let mnemonic = self.operator.ope_kind.mnemonic();
+        let [low, _] = self.operands;
+        let addr = self.operand();
+        match self.operator.addr_mode {
+            AddrMode::Impl | AddrMode::Nop => defmt::write!(f, "{=str}", mnemonic),
+            AddrMode::Acc => defmt::write!(f, "{=str} A", mnemonic),
+            AddrMode::Imm => defmt::write!(f, "{=str} #${=u8:02X}", mnemonic, low),
+            AddrMode::Zp => defmt::write!(f, "{=str} ${=u8:02X}", mnemonic, low),
+            AddrMode::ZpX => defmt::write!(f, "{=str} ${=u8:02X},X", mnemonic, low),
+            AddrMode::ZpY => defmt::write!(f, "{=str} ${=u8:02X},Y", mnemonic, low),
+            AddrMode::Abs => defmt::write!(f, "{=str} ${=u16:04X}", mnemonic, addr),
+            AddrMode::AbsX => defmt::write!(f, "{=str} ${=u16:04X},X", mnemonic, addr),
+            AddrMode::AbsY => defmt::write!(f, "{=str} ${=u16:04X},Y", mnemonic, addr),
+            AddrMode::Ind => defmt::write!(f, "{=str} (${=u16:04X})", mnemonic, addr),
+            AddrMode::IndX => defmt::write!(f, "{=str} (${=u8:02X},X)", mnemonic, low),
+            AddrMode::IndY => defmt::write!(f, "{=str} (${=u8:02X}),Y", mnemonic, low),
+            AddrMode::Rel => match self.branch_target() {
+                Some(target) => defmt::write!(f, "{=str} ${=u16:04X}", mnemonic, target),
+                None => defmt::write!(f, "{=str} *{=i16}", mnemonic, low as i8 as i16 + 2),
+            },
+        }
+    }
+}
+
+/// nestest-style line for the instruction about to run with `registers`, e.g.
+///
+/// ```text
+/// C72A  B0 04     BCS $C730                       A:00 X:00 Y:00 P:24 SP:FD CYC:21
+/// ```
+///
+/// Illegal opcodes are marked with `*` before the mnemonic. `CYC` is left out when the
+/// cycle count is unknown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceLine {
+    pub instruction: Instruction,
+    pub registers: Registers,
+    pub cycle: Option<u64>,
+}
+
+impl TraceLine {
+    /// Decodes `bytes` at `registers.pc`.
+    pub fn new(bytes: &[u8], registers: Registers, cycle: Option<u64>) -> Option<Self> {
+        Some(Self {
+            instruction: Instruction::decode(bytes)?.at(registers.pc),
+            registers,
+            cycle,
+        })
+    }
+}
+
+impl fmt::Display for TraceLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let r = &self.registers;
+        let mut column = Column { f, len: 0 };
+        fmt::write(
+            &mut column,
+            format_args!("{:04X}  {:02X}", r.pc, self.instruction.opcode),
+        )?;
+        for operand in self.instruction.operands() {
+            fmt::write(&mut column, format_args!(" {:02X}", operand))?;
+        }
+        column.pad_to(MNEMONIC_COLUMN)?;
+        let mark = if self.instruction.is_illegal() {
+            '*'
+        } else {
+            ' '
+        };
+        fmt::write(&mut column, format_args!("{}{}", mark, self.instruction))?;
+        column.pad_to(REGISTERS_COLUMN)?;
+        write!(
+            f,
+            "A:{:02X} X:{:02X} Y:{:02X} P:{:02X} SP:{:02X}",
+            r.a, r.x, r.y, r.p, r.s
+        )?;
+        match self.cycle {
+            Some(cycle) => write!(f, " CYC:{}", cycle),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writer that counts what went through it, so that columns line up without allocating.
+struct Column<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    len: usize,
+}
+
+impl Column<'_, '_> {
+    /// Writes spaces up to `column`, or a single one if the text already reaches it.
+    fn pad_to(&mut self, column: usize) -> fmt::Result {
+        let spaces = column.saturating_sub(self.len).max(1);
+        for _ in 0..spaces {
+            fmt::Write::write_char(self, ' ')?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Write for Column<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.len += s.chars().count();
+        self.f.write_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    fn registers(a: u8, x: u8, y: u8, p: u8, s: u8, pc: u16) -> Registers {
+        Registers { a, x, y, s, p, pc }
+    }
+
+    fn text(bytes: &[u8]) -> std::string::String {
+        Instruction::decode(bytes).unwrap().to_string()
+    }
+
+    #[test]
+    fn renders_every_addressing_mode() {
+        assert_eq!(text(&[0xEA]), "NOP");
+        assert_eq!(text(&[0x1A]), "NOP");
+        assert_eq!(text(&[0x0A]), "ASL A");
+        assert_eq!(text(&[0xA9, 0x10]), "LDA #$10");
+        assert_eq!(text(&[0xA5, 0x10]), "LDA $10");
+        assert_eq!(text(&[0xB5, 0x10]), "LDA $10,X");
+        assert_eq!(text(&[0xB6, 0x10]), "LDX $10,Y");
+        assert_eq!(text(&[0xAD, 0x00, 0x04]), "LDA $0400");
+        assert_eq!(text(&[0xBD, 0x00, 0x04]), "LDA $0400,X");
+        assert_eq!(text(&[0xB9, 0x00, 0x04]), "LDA $0400,Y");
+        assert_eq!(text(&[0x6C, 0xFF, 0x02]), "JMP ($02FF)");
+        assert_eq!(text(&[0xA1, 0x80]), "LDA ($80,X)");
+        assert_eq!(text(&[0xB1, 0x80]), "LDA ($80),Y");
+        assert_eq!(text(&[0xF0, 0x04]), "BEQ *+6");
+        assert_eq!(text(&[0xF0, 0xFC]), "BEQ *-2");
+    }
+
+    #[test]
+    fn placed_branches_print_their_target() {
+        let beq = |offset| Instruction::decode(&[0xF0, offset]).unwrap().at(0xC000);
+        assert_eq!(beq(0x04).to_string(), "BEQ $C006");
+        assert_eq!(beq(0xFC).to_string(), "BEQ $BFFE");
+        assert_eq!(beq(0x04).branch_target(), Some(0xC006));
+        let jmp = Instruction::decode(&[0x4C, 0x00, 0xC0]).unwrap().at(0xC000);
+        assert_eq!(jmp.branch_target(), None);
+    }
+
+    #[test]
+    fn decode_needs_every_operand() {
+        assert_eq!(Instruction::decode(&[]), None);
+        assert_eq!(Instruction::decode(&[0xAD, 0x00]), None);
+        // bytes past the instruction are ignored.
+        let lda = Instruction::decode(&[0xA9, 0x10, 0x86]).unwrap();
+        assert_eq!(lda.operands(), [0x10]);
+    }
+
+    // lines of the Nintendulator nestest.log without the PPU column and the `= 00` memory
+    // annotations, which the model does not produce.
+    #[test]
+    fn trace_lines_line_up_with_nestest_log() {
+        let line = |bytes: &[u8], registers, cycle| {
+            TraceLine::new(bytes, registers, cycle).unwrap().to_string()
+        };
+        assert_eq!(
+            line(
+                &[0x4C, 0xF5, 0xC5],
+                registers(0, 0, 0, 0x24, 0xFD, 0xC000),
+                Some(7)
+            ),
+            "C000  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD CYC:7"
+        );
+        assert_eq!(
+            line(
+                &[0xA2, 0x00],
+                registers(0, 0, 0, 0x24, 0xFD, 0xC5F5),
+                Some(10)
+            ),
+            "C5F5  A2 00     LDX #$00                        A:00 X:00 Y:00 P:24 SP:FD CYC:10"
+        );
+        assert_eq!(
+            line(
+                &[0x86, 0x00],
+                registers(0, 0, 0, 0x26, 0xFD, 0xC5F7),
+                Some(13)
+            ),
+            "C5F7  86 00     STX $00                         A:00 X:00 Y:00 P:26 SP:FD CYC:13"
+        );
+        // branches print where they go, taken or not. Registers from the bundled log.
+        assert_eq!(
+            line(&[0xB0, 0x04], registers(0, 0, 0, 0x27, 0xFB, 0xC72F), None),
+            "C72F  B0 04     BCS $C735                       A:00 X:00 Y:00 P:27 SP:FB"
+        );
+        assert_eq!(
+            line(&[0xB0, 0x03], registers(0, 0, 0, 0x26, 0xFB, 0xC737), None),
+            "C737  B0 03     BCS $C73C                       A:00 X:00 Y:00 P:26 SP:FB"
+        );
+    }
+
+    // registers from the bundled nestest.log, which names the illegal NOPs DOP and TOP.
+    #[test]
+    fn trace_lines_mark_illegal_opcodes() {
+        let line =
+            |bytes: &[u8], registers| TraceLine::new(bytes, registers, None).unwrap().to_string();
+        assert_eq!(
+            line(
+                &[0x04, 0xA9],
+                registers(0xAA, 0x97, 0x4E, 0xEF, 0xF9, 0xC6BD)
+            ),
+            "C6BD  04 A9    *DOP $A9                         A:AA X:97 Y:4E P:EF SP:F9"
+        );
+        assert_eq!(
+            line(
+                &[0x0C, 0xA9, 0xA9],
+                registers(0xAA, 0x97, 0x4E, 0xEF, 0xF7, 0xC6C9)
+            ),
+            "C6C9  0C A9 A9 *TOP $A9A9                       A:AA X:97 Y:4E P:EF SP:F7"
+        );
+        assert_eq!(
+            line(
+                &[0xA3, 0x40],
+                registers(0x00, 0x03, 0x77, 0x67, 0xFB, 0xE545)
+            ),
+            "E545  A3 40    *LAX ($40,X)                     A:00 X:03 Y:77 P:67 SP:FB"
+        );
+        // a copy of SBC #imm, and the illegal and the documented NOP.
+        assert_eq!(
+            line(
+                &[0xEB, 0x40],
+                registers(0x40, 0xEF, 0x90, 0x65, 0xFB, 0xE8D8)
+            ),
+            "E8D8  EB 40    *SBC #$40                        A:40 X:EF Y:90 P:65 SP:FB"
+        );
+        assert_eq!(
+            line(&[0x1A], registers(0xAA, 0x97, 0x4E, 0xEF, 0xF3, 0xC6E4)),
+            "C6E4  1A       *NOP                             A:AA X:97 Y:4E P:EF SP:F3"
+        );
+        assert_eq!(
+            line(&[0xEA], registers(0x00, 0x00, 0x00, 0x26, 0xFB, 0xC72D)),
+            "C72D  EA        NOP                             A:00 X:00 Y:00 P:26 SP:FB"
+        );
+    }
+
+    #[test]
+    fn columns_do_not_move_with_the_instruction_length() {
+        let r = registers(0, 0, 0, 0x24, 0xFD, 0x0600);
+        for bytes in [
+            &[0xEA][..],
+            &[0xA1, 0x80],
+            &[0x6C, 0xFF, 0x02],
+            &[0x0C, 0x00, 0x04],
+        ] {
+            let line = TraceLine::new(bytes, r, Some(7)).unwrap().to_string();
+            let mark = line.as_bytes()[MNEMONIC_COLUMN];
+            assert!(mark == b' ' || mark == b'*', "{}", line);
+            assert!(
+                line.as_bytes()[MNEMONIC_COLUMN + 1].is_ascii_uppercase(),
+                "{}",
+                line
+            );
+            assert!(line[..MNEMONIC_COLUMN].ends_with(' '), "{}", line);
+            assert_eq!(line.find("A:"), Some(REGISTERS_COLUMN), "{}", line);
+            assert!(line.ends_with(" CYC:7"), "{}", line);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+#![macro_use]
+
+// Logs through defmt when the `defmt` feature is enabled and compiles to nothing otherwise,
+// so the host crates can share the helpers without an rtt logger.
+//
+// The feature is checked here, where the macro is defined, rather than inside its expansion,
+// where `cfg` would look at the calling crate's features. A caller that enables this crate's
+// `defmt` feature must depend on defmt itself, since defmt's own macros name `defmt::`.
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! info {
+    ($s:literal $(, $x:expr)* $(,)?) => {
+        ::defmt::info!($s $(, $x)*)
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[macro_export]
+macro_rules! info {
+    ($s:literal $(, $x:expr)* $(,)?) => {
+        {
+            let _ = ($( & $x ),*);
+        }
+    };
+}
@@ -1,3 +1,10 @@
 #![no_std]
 
+mod fmt;
+
+pub mod asm;
+pub mod cpu;
+pub mod disasm;
+pub mod memory_map;
 pub mod middleware;
+pub mod operators;
@@ -0,0 +1,339 @@
+//! Where a cpu address lands, decoded into the regions of `CpuMemoryMapPkg` as the FPGA
+//! `Ram` module does.
+//!
+//! Addresses are folded as on a NES, where $0800-$1FFF repeat $0000-$07FF and $2008-$3FFF
+//! repeat $2000-$2007. [`divergence`] tells where the FPGA does something else, such as
+//! keeping a mirror in its own block ram.
+
+use crate::middleware::mode::CassetteMode;
+
+/// Regions of `CpuMemoryMapEnum`, declared in the same order.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Region {
+    Wram,
+    WramMirror,
+    PpuRegister,
+    PpuRegisterMirror,
+    /// APU and I/O registers of the 2A03.
+    Cpu2A03,
+    /// Test mode registers of the 2A03, `ip_func_apu_io` in `Ram`.
+    ApuIo,
+    Erom,
+    Eram,
+    PrgRom1,
+    PrgRom2,
+}
+
+/// Every region in address order.
+pub const REGIONS: [Region; 10] = [
+    Region::Wram,
+    Region::WramMirror,
+    Region::PpuRegister,
+    Region::PpuRegisterMirror,
+    Region::Cpu2A03,
+    Region::ApuIo,
+    Region::Erom,
+    Region::Eram,
+    Region::PrgRom1,
+    Region::PrgRom2,
+];
+
+impl Region {
+    pub const fn start(self) -> u16 {
+        match self {
+            Region::Wram => 0x0000,
+            Region::WramMirror => 0x0800,
+            Region::PpuRegister => 0x2000,
+            Region::PpuRegisterMirror => 0x2008,
+            Region::Cpu2A03 => 0x4000,
+            Region::ApuIo => 0x4018,
+            Region::Erom => 0x4020,
+            Region::Eram => 0x6000,
+            Region::PrgRom1 => 0x8000,
+            Region::PrgRom2 => 0xC000,
+        }
+    }
+
+    /// Last address of the region.
+    pub const fn end(self) -> u16 {
+        match self {
+            Region::Wram => 0x07FF,
+            Region::WramMirror => 0x1FFF,
+            Region::PpuRegister => 0x2007,
+            Region::PpuRegisterMirror => 0x3FFF,
+            Region::Cpu2A03 => 0x4017,
+            Region::ApuIo => 0x401F,
+            Region::Erom => 0x5FFF,
+            Region::Eram => 0x7FFF,
+            Region::PrgRom1 => 0xBFFF,
+            Region::PrgRom2 => 0xFFFF,
+        }
+    }
+
+    /// Number of addresses in the region.
+    pub const fn size(self) -> usize {
+        (self.end() - self.start()) as usize + 1
+    }
+
+    /// Region this one repeats on a NES.
+    pub const fn mirror_of(self) -> Option<Region> {
+        match self {
+            Region::WramMirror => Some(Region::Wram),
+            Region::PpuRegisterMirror => Some(Region::PpuRegister),
+            _ => None,
+        }
+    }
+
+    /// Whether `Ram` decodes the region but has no storage behind it yet. Writes are dropped
+    /// and reads return whatever was read before.
+    pub const fn is_unimplemented(self) -> bool {
+        matches!(self, Region::PpuRegister | Region::Cpu2A03)
+    }
+
+    pub const fn contains(self, addr: u16) -> bool {
+        self.start() <= addr && addr <= self.end()
+    }
+}
+
+/// Address decoded into its region.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub region: Region,
+    /// Offset from the start of the region, which is the address `Ram` gives its block ram.
+    pub offset: u16,
+}
+
+impl Location {
+    pub const fn addr(self) -> u16 {
+        self.region.start() + self.offset
+    }
+}
+
+pub const fn decode(addr: u16) -> Location {
+    let region = match addr {
+        0x0000..=0x07FF => Region::Wram,
+        0x0800..=0x1FFF => Region::WramMirror,
+        0x2000..=0x2007 => Region::PpuRegister,
+        0x2008..=0x3FFF => Region::PpuRegisterMirror,
+        0x4000..=0x4017 => Region::Cpu2A03,
+        0x4018..=0x401F => Region::ApuIo,
+        0x4020..=0x5FFF => Region::Erom,
+        0x6000..=0x7FFF => Region::Eram,
+        0x8000..=0xBFFF => Region::PrgRom1,
+        _ => Region::PrgRom2,
+    };
+    Location {
+        region,
+        offset: addr - region.start(),
+    }
+}
+
+/// Address a NES really accesses for `addr`, with the wram and ppu register mirrors folded
+/// onto the region they repeat.
+pub const fn mirror(addr: u16) -> u16 {
+    match decode(addr).region {
+        Region::WramMirror => addr & 0x07FF,
+        Region::PpuRegisterMirror => 0x2000 | (addr & 0x0007),
+        _ => addr,
+    }
+}
+
+/// Whether `a` and `b` are the same byte on a NES.
+pub const fn aliases(a: u16, b: u16) -> bool {
+    mirror(a) == mirror(b)
+}
+
+/// How the FPGA `Ram` module differs from a NES at an address.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Divergence {
+    /// The mirror has its own block ram, so it does not see what was written at `of`, the
+    /// address it repeats, and the other way around.
+    SeparateMirror { of: u16 },
+    /// No storage behind the address, see [`Region::is_unimplemented`].
+    Unimplemented,
+    /// `ip_ppu_reg_mirror` has [`PPU_REG_MIRROR_DEPTH`] words, fewer than the addresses of
+    /// the mirror, so nothing is stored from $3808 on. Treated as `Unimplemented`.
+    BeyondDepth,
+    /// `Ram` only writes prg_rom2 from $CFFF, so writes below it are dropped.
+    WriteDropped,
+    /// Outside `CassetteMode::NesTest`, `Ram` builds prg_rom1 and prg_rom2 from `ip_eram`
+    /// instead of the 16KiB `ip_prg1_rom` and `ip_prg2_rom`. Each holds [`PRG_ROM_DEPTH`]
+    /// words, so its upper half is the same byte as `of`.
+    ShortRom { of: u16 },
+    /// `ip_func_apu_io` is written with `i_we` itself, so every write also lands at `at`.
+    /// That happens besides any other divergence, which is reported over this one.
+    ApuIoEcho { at: u16 },
+}
+
+/// Words of `ip_ppu_reg_mirror`.
+pub const PPU_REG_MIRROR_DEPTH: usize = 6144;
+
+/// Words of `ip_eram`, which also backs prg_rom1 and prg_rom2.
+pub const PRG_ROM_DEPTH: usize = 8192;
+
+/// Where an access to `addr` on the FPGA does not behave as on a NES, if it does not.
+pub const fn divergence(addr: u16, write: bool, cassette_mode: CassetteMode) -> Option<Divergence> {
+    let location = decode(addr);
+    let region = location.region;
+    if region.is_unimplemented() {
+        return Some(Divergence::Unimplemented);
+    }
+    if matches!(region, Region::PpuRegisterMirror)
+        && location.offset as usize >= PPU_REG_MIRROR_DEPTH
+    {
+        return Some(Divergence::BeyondDepth);
+    }
+    if region.mirror_of().is_some() {
+        return Some(Divergence::SeparateMirror { of: mirror(addr) });
+    }
+    let nestest = matches!(cassette_mode, CassetteMode::NesTest);
+    match region {
+        Region::PrgRom2 if write && addr < 0xCFFF => Some(Divergence::WriteDropped),
+        Region::PrgRom1 | Region::PrgRom2
+            if !nestest && location.offset as usize >= PRG_ROM_DEPTH =>
+        {
+            Some(Divergence::ShortRom {
+                of: addr - PRG_ROM_DEPTH as u16,
+            })
+        }
+        Region::ApuIo => None,
+        _ if write => Some(Divergence::ApuIoEcho {
+            at: apu_io_echo(addr),
+        }),
+        _ => None,
+    }
+}
+
+/// Address in the test mode registers that a write to `addr` also lands at, as
+/// `ip_func_apu_io` only takes the low bits of `addr - $4018`.
+pub const fn apu_io_echo(addr: u16) -> u16 {
+    Region::ApuIo.start() + (addr.wrapping_sub(Region::ApuIo.start()) & 0x7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions_cover_the_address_space_in_order() {
+        assert_eq!(REGIONS[0].start(), 0x0000);
+        assert_eq!(REGIONS[REGIONS.len() - 1].end(), 0xFFFF);
+        for pair in REGIONS.windows(2) {
+            assert_eq!(pair[0].end() + 1, pair[1].start(), "{:?}", pair);
+        }
+        assert_eq!(
+            REGIONS.iter().map(|region| region.size()).sum::<usize>(),
+            0x10000
+        );
+    }
+
+    #[test]
+    fn decodes_every_region_boundary() {
+        for region in REGIONS {
+            let first = decode(region.start());
+            assert_eq!((first.region, first.offset), (region, 0));
+            let last = decode(region.end());
+            assert_eq!(
+                (last.region, last.offset as usize),
+                (region, region.size() - 1)
+            );
+            assert_eq!(last.addr(), region.end());
+            assert!(region.contains(region.start()) && region.contains(region.end()));
+        }
+        assert_eq!(decode(0x0800).region, Region::WramMirror);
+        assert_eq!(decode(0x2008).region, Region::PpuRegisterMirror);
+        assert_eq!(decode(0x4018).region, Region::ApuIo);
+        assert_eq!(decode(0xC000).region, Region::PrgRom2);
+    }
+
+    #[test]
+    fn mirrors_fold_onto_what_they_repeat() {
+        assert_eq!(mirror(0x0800), 0x0000);
+        assert_eq!(mirror(0x1FFF), 0x07FF);
+        assert_eq!(mirror(0x1234), 0x0234);
+        assert_eq!(mirror(0x2008), 0x2000);
+        assert_eq!(mirror(0x3FFF), 0x2007);
+        // anything else is left alone.
+        assert_eq!(mirror(0x07FF), 0x07FF);
+        assert_eq!(mirror(0x4018), 0x4018);
+        assert!(aliases(0x0800, 0x0000));
+        assert!(aliases(0x1FFF, 0x07FF));
+        assert!(aliases(0x1800, 0x1000));
+        assert!(aliases(0x3456, 0x2006));
+        assert!(!aliases(0x0800, 0x0801));
+        assert!(!aliases(0x2000, 0x4000));
+    }
+
+    #[test]
+    fn mirrors_are_separate_up_to_the_ppu_mirror_depth() {
+        let read = |addr| divergence(addr, false, CassetteMode::None);
+        assert_eq!(
+            read(0x0800),
+            Some(Divergence::SeparateMirror { of: 0x0000 })
+        );
+        assert_eq!(
+            read(0x1FFF),
+            Some(Divergence::SeparateMirror { of: 0x07FF })
+        );
+        let last = 0x2008 + PPU_REG_MIRROR_DEPTH as u16 - 1;
+        assert_eq!(last, 0x3807);
+        assert_eq!(read(last), Some(Divergence::SeparateMirror { of: 0x2007 }));
+        assert_eq!(read(last + 1), Some(Divergence::BeyondDepth));
+        assert_eq!(read(0x3FFF), Some(Divergence::BeyondDepth));
+    }
+
+    #[test]
+    fn unimplemented_roms_and_dropped_writes() {
+        let none = CassetteMode::None;
+        let nestest = CassetteMode::NesTest;
+        assert_eq!(
+            divergence(0x2000, false, none),
+            Some(Divergence::Unimplemented)
+        );
+        assert_eq!(
+            divergence(0x4017, true, none),
+            Some(Divergence::Unimplemented)
+        );
+        assert!(Region::PpuRegister.is_unimplemented() && !Region::Wram.is_unimplemented());
+        assert_eq!(
+            divergence(0xCFFE, true, nestest),
+            Some(Divergence::WriteDropped)
+        );
+        assert_eq!(divergence(0xC000, false, nestest), None);
+        assert_eq!(divergence(0x9FFF, false, none), None);
+        assert_eq!(
+            divergence(0xA000, false, none),
+            Some(Divergence::ShortRom { of: 0x8000 })
+        );
+        assert_eq!(
+            divergence(0xFFFC, false, none),
+            Some(Divergence::ShortRom { of: 0xDFFC })
+        );
+        assert_eq!(divergence(0xA000, false, nestest), None);
+    }
+
+    #[test]
+    fn writes_echo_into_the_apu_io_registers() {
+        assert_eq!(apu_io_echo(0x4018), 0x4018);
+        assert_eq!(apu_io_echo(0x401F), 0x401F);
+        assert_eq!(apu_io_echo(0x4020), 0x4018);
+        assert_eq!(apu_io_echo(0x0000), 0x4018);
+        assert_eq!(apu_io_echo(0x0001), 0x4019);
+        assert_eq!(apu_io_echo(0x6007), 0x401F);
+        let write = |addr| divergence(addr, true, CassetteMode::NesTest);
+        assert_eq!(write(0x0001), Some(Divergence::ApuIoEcho { at: 0x4019 }));
+        assert_eq!(write(0x6000), Some(Divergence::ApuIoEcho { at: 0x4018 }));
+        assert_eq!(write(0xCFFF), Some(Divergence::ApuIoEcho { at: 0x401F }));
+        // reads do not echo, the registers themselves are not echoed into, and other
+        // divergences are reported over the echo.
+        assert_eq!(divergence(0x0001, false, CassetteMode::NesTest), None);
+        assert_eq!(write(0x401A), None);
+        assert_eq!(
+            write(0x0800),
+            Some(Divergence::SeparateMirror { of: 0x0000 })
+        );
+    }
+}
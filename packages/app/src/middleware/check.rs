@@ -0,0 +1,146 @@
+use crate::cpu::{FlagDiff, StatusFlags};
+use crate::middleware::checkpoint::TableError;
+use crate::middleware::mode::command::FrameError;
+use crate::middleware::mode::TxReg;
+use crate::middleware::state::StateDiff;
+use crate::middleware::transport::Transport;
+
+/// Up to [`Bytes::CAPACITY`] bytes sent or received in a single check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bytes {
+    buf: [u8; Bytes::CAPACITY],
+    len: usize,
+}
+
+impl Bytes {
+    /// Longest frame a check compares, the `[addr_low, addr_high, data]` of a mocked write.
+    pub const CAPACITY: usize = 3;
+
+    /// Copies `data`, dropping anything past [`Bytes::CAPACITY`].
+    pub fn new(data: &[u8]) -> Self {
+        let len = data.len().min(Self::CAPACITY);
+        let mut buf = [0x0u8; Self::CAPACITY];
+        buf[..len].copy_from_slice(&data[..len]);
+        Self { buf, len }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Bytes {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=[u8]:X}", self.as_slice())
+    }
+}
+
+/// What a failed check was looking at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckKind<E> {
+    /// Reply of `OpeMode::RegisterTransfer`.
+    Register(TxReg),
+    /// Snapshot of every register, compared as a whole by `check_state`.
+    State(StateDiff),
+    /// Bytes the FPGA sent on its own, such as mocked memory accesses.
+    Read,
+    /// Byte at an address of the memory behind mock mode, compared by `check_memory`.
+    Memory(u16),
+    /// RW pin. Levels are compared as `[1]` for high and `[0]` for low.
+    Rw,
+    /// Nop pin after RESB. Levels are compared as for `Rw`.
+    Reset,
+    /// The command could not be encoded, so nothing was sent.
+    Frame(FrameError),
+    /// The checkpoint table could not be read, so nothing was sent.
+    Table(TableError),
+    /// The transport itself failed, so there is nothing to compare.
+    Transport(E),
+}
+
+// transport errors are left out so that any `Transport` can be logged.
+#[cfg(feature = "defmt")]
+impl<E> defmt::Format for CheckKind<E> {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            CheckKind::Register(register) => defmt::write!(f, "register {}", register),
+            CheckKind::State(_) => defmt::write!(f, "state"),
+            CheckKind::Read => defmt::write!(f, "read"),
+            CheckKind::Memory(addr) => defmt::write!(f, "memory {=u16:04X}", addr),
+            CheckKind::Rw => defmt::write!(f, "rw"),
+            CheckKind::Reset => defmt::write!(f, "reset"),
+            CheckKind::Frame(error) => defmt::write!(f, "frame {}", error),
+            CheckKind::Table(error) => defmt::write!(f, "table {}", error),
+            CheckKind::Transport(_) => defmt::write!(f, "transport"),
+        }
+    }
+}
+
+/// Failure of a check helper.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CheckError<E> {
+    /// Name of the failed test. Empty until [`TestRunner`](crate::middleware::runner::TestRunner) fills it in.
+    pub test_name: &'static str,
+    pub kind: CheckKind<E>,
+    pub expected: Bytes,
+    pub actual: Bytes,
+}
+
+impl<E> CheckError<E> {
+    pub fn new(kind: CheckKind<E>, expected: &[u8], actual: &[u8]) -> Self {
+        Self {
+            test_name: "",
+            kind,
+            expected: Bytes::new(expected),
+            actual: Bytes::new(actual),
+        }
+    }
+
+    pub fn transport(error: E) -> Self {
+        Self::new(CheckKind::Transport(error), &[], &[])
+    }
+
+    /// Flags that differ when the check compared the P register.
+    pub fn flag_diff(&self) -> Option<FlagDiff> {
+        match (&self.kind, self.expected.as_slice(), self.actual.as_slice()) {
+            (CheckKind::Register(TxReg::P), [expected], [actual]) => {
+                Some(StatusFlags::from(*expected).diff(StatusFlags::from(*actual)))
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn level(kind: CheckKind<E>, expect_high: bool) -> Self {
+        Self::new(kind, &[expect_high as u8], &[!expect_high as u8])
+    }
+}
+
+impl<E> From<FrameError> for CheckError<E> {
+    fn from(error: FrameError) -> Self {
+        Self::new(CheckKind::Frame(error), &[], &[])
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<E> defmt::Format for CheckError<E> {
+    fn format(&self, f: defmt::Formatter) {
+        if let CheckKind::State(diff) = &self.kind {
+            return defmt::write!(f, "{} failed at state. {}", self.test_name, diff);
+        }
+        defmt::write!(
+            f,
+            "{} failed at {}. expect value is {}, but return value is {}",
+            self.test_name,
+            self.kind,
+            self.expected,
+            self.actual
+        );
+        if let Some(diff) = self.flag_diff() {
+            defmt::write!(f, ". {}", diff)
+        }
+    }
+}
+
+/// Result of a check helper or a whole test driven through `T`.
+pub type CheckResult<T> = Result<(), CheckError<<T as Transport>::Error>>;
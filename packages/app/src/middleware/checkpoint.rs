@@ -0,0 +1,374 @@
+//! Checkpoint tables written by `dump_sequence` and run against the FPGA.
+//!
+//! A table is a [`HEADER_LEN`] byte header followed by [`RECORD_LEN`] byte records, all
+//! little endian.
+//!
+//! ```text
+//! header: "FC2C" version:u8 mode:u8 reserved:u16 count:u32
+//! record: line:u32 steps:u16 a x y s p pc:u16 instruction:[u8; 3]
+//! ```
+
+use crate::cpu::Registers;
+use crate::disasm::Instruction;
+use crate::middleware::check::{CheckError, CheckKind, CheckResult};
+use crate::middleware::mode::command::{operand_len, Command};
+use crate::middleware::state::check_state;
+use crate::middleware::transport::{ControlPins, Transport};
+use crate::middleware::util::send_command;
+
+/// Instructions run by one `OpeMode::Sequence` frame when stepping a long way.
+pub const SEQUENCE_BATCH: u8 = 200;
+
+pub const MAGIC: [u8; 4] = *b"FC2C";
+pub const VERSION: u8 = 1;
+pub const HEADER_LEN: usize = 12;
+pub const RECORD_LEN: usize = 16;
+
+/// How the firmware goes through a table.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableMode {
+    /// Checks every record in order and stops at the first mismatch.
+    InOrder,
+    /// Binary searches the records for the first mismatch.
+    Bisect,
+}
+
+/// Table the firmware cannot interpret.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableError {
+    Magic,
+    Version(u8),
+    Mode(u8),
+    /// The table is shorter or longer than its header says.
+    Length {
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// Registers expected before the instruction on `line` of nestest.log runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub line: u32,
+    /// Instructions to run after the previous record, or after the start of the table.
+    pub steps: u16,
+    pub registers: Registers,
+    /// Opcode and operands on `line`, padded with zeros. Only used to report a mismatch.
+    pub instruction: [u8; 3],
+}
+
+impl Checkpoint {
+    pub fn to_bytes(&self) -> [u8; RECORD_LEN] {
+        let r = &self.registers;
+        let line = self.line.to_le_bytes();
+        let steps = self.steps.to_le_bytes();
+        let pc = r.pc.to_le_bytes();
+        let [op, op1, op2] = self.instruction;
+        [
+            line[0], line[1], line[2], line[3], steps[0], steps[1], r.a, r.x, r.y, r.s, r.p, pc[0],
+            pc[1], op, op1, op2,
+        ]
+    }
+
+    pub fn from_bytes(bytes: &[u8; RECORD_LEN]) -> Self {
+        let b = bytes;
+        Self {
+            line: u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            steps: u16::from_le_bytes([b[4], b[5]]),
+            registers: Registers {
+                a: b[6],
+                x: b[7],
+                y: b[8],
+                s: b[9],
+                p: b[10],
+                pc: u16::from_le_bytes([b[11], b[12]]),
+            },
+            instruction: [b[13], b[14], b[15]],
+        }
+    }
+
+    /// Opcode and the operands it actually takes.
+    pub fn instruction(&self) -> &[u8] {
+        &self.instruction[..1 + operand_len(self.instruction[0])]
+    }
+
+    /// Instruction on `line`, placed at its pc.
+    pub fn disassembly(&self) -> Option<Instruction> {
+        Some(Instruction::decode(self.instruction())?.at(self.registers.pc))
+    }
+}
+
+/// Header of a table holding `count` records.
+pub fn header(mode: TableMode, count: u32) -> [u8; HEADER_LEN] {
+    let [m0, m1, m2, m3] = MAGIC;
+    let [c0, c1, c2, c3] = count.to_le_bytes();
+    [m0, m1, m2, m3, VERSION, mode as u8, 0, 0, c0, c1, c2, c3]
+}
+
+/// Checkpoint table borrowed from flash or a buffer.
+#[derive(Clone, Copy, Debug)]
+pub struct CheckpointTable<'a> {
+    mode: TableMode,
+    records: &'a [u8],
+}
+
+impl<'a> CheckpointTable<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, TableError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(TableError::Length {
+                expected: HEADER_LEN,
+                actual: bytes.len(),
+            });
+        }
+        let (header, records) = bytes.split_at(HEADER_LEN);
+        if header[..4] != MAGIC {
+            return Err(TableError::Magic);
+        }
+        if header[4] != VERSION {
+            return Err(TableError::Version(header[4]));
+        }
+        let mode = match header[5] {
+            0 => TableMode::InOrder,
+            1 => TableMode::Bisect,
+            mode => return Err(TableError::Mode(mode)),
+        };
+        let count = u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+        if records.len() != count * RECORD_LEN {
+            return Err(TableError::Length {
+                expected: HEADER_LEN + count * RECORD_LEN,
+                actual: bytes.len(),
+            });
+        }
+        Ok(Self { mode, records })
+    }
+
+    pub fn mode(&self) -> TableMode {
+        self.mode
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len() / RECORD_LEN
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Checkpoint> {
+        let record = self
+            .records
+            .get(index * RECORD_LEN..(index + 1) * RECORD_LEN)?;
+        Some(Checkpoint::from_bytes(record.try_into().ok()?))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Checkpoint> + 'a {
+        self.records
+            .chunks_exact(RECORD_LEN)
+            .filter_map(|record| Some(Checkpoint::from_bytes(record.try_into().ok()?)))
+    }
+}
+
+/// Runs `count` instructions with as few `OpeMode::Sequence` frames as possible.
+pub fn step<T: Transport>(transport: &mut T, mut count: u32) -> CheckResult<T> {
+    while count > 0 {
+        let batch = count.min(SEQUENCE_BATCH as u32);
+        send_command(transport, Command::Sequence(batch as u8))?;
+        count -= batch;
+    }
+    Ok(())
+}
+
+/// Runs `table` the way its mode says. `setup` has to reset the FPGA and leave the cpu where
+/// the table starts.
+pub fn run_table<D: Transport + ControlPins>(
+    fc2: &mut D,
+    table: &CheckpointTable,
+    mut setup: impl FnMut(&mut D) -> CheckResult<D>,
+) -> CheckResult<D> {
+    match table.mode() {
+        TableMode::InOrder => {
+            setup(fc2)?;
+            run_checkpoints(fc2, table)
+        }
+        TableMode::Bisect => bisect_checkpoints(fc2, table, setup),
+    }
+}
+
+/// Steps through `table` from where the cpu is and stops at the first record whose registers
+/// differ.
+pub fn run_checkpoints<T: Transport>(transport: &mut T, table: &CheckpointTable) -> CheckResult<T> {
+    for checkpoint in table.iter() {
+        step(transport, checkpoint.steps as u32)?;
+        if let Err(error) = check_state(transport, &checkpoint.registers) {
+            info!(
+                "first mismatch at nestest.log line {}: {:04X} {}",
+                checkpoint.line,
+                checkpoint.registers.pc,
+                checkpoint.disassembly()
+            );
+            return Err(error);
+        }
+        info!("line {} passed.", checkpoint.line);
+    }
+    Ok(())
+}
+
+/// Binary searches `table` for the first record whose registers differ and returns its
+/// mismatch.
+///
+/// Every probe starts over from `setup`, which has to reset the FPGA and leave the cpu where
+/// the table starts. Lines after a divergent one are assumed to diverge as well.
+pub fn bisect_checkpoints<D: Transport + ControlPins>(
+    fc2: &mut D,
+    table: &CheckpointTable,
+    mut setup: impl FnMut(&mut D) -> CheckResult<D>,
+) -> CheckResult<D> {
+    let (mut low, mut high) = (0, table.len());
+    let mut mismatch = None;
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let steps = table
+            .iter()
+            .take(middle + 1)
+            .map(|checkpoint| checkpoint.steps as u32)
+            .sum();
+        let Some(checkpoint) = table.get(middle) else {
+            break;
+        };
+        info!("probe line {}.", checkpoint.line);
+        setup(fc2)?;
+        step(fc2, steps)?;
+        match check_state(fc2, &checkpoint.registers) {
+            Ok(()) => low = middle + 1,
+            Err(
+                error @ CheckError {
+                    kind: CheckKind::State(_),
+                    ..
+                },
+            ) => {
+                high = middle;
+                mismatch = Some(error);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    match (table.get(low), mismatch) {
+        (Some(checkpoint), Some(error)) => {
+            info!(
+                "first divergent nestest.log line is {}: {:04X} {}",
+                checkpoint.line,
+                checkpoint.registers.pc,
+                checkpoint.disassembly()
+            );
+            Err(error)
+        }
+        _ => {
+            info!("no divergence up to the last checkpoint.");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKPOINT: Checkpoint = Checkpoint {
+        line: 0x0001_0203,
+        steps: 0x0405,
+        registers: Registers {
+            a: 0x06,
+            x: 0x07,
+            y: 0x08,
+            s: 0x09,
+            p: 0x0A,
+            pc: 0x0C0B,
+        },
+        instruction: [0x4C, 0xF5, 0xC5],
+    };
+
+    fn table(count: u32) -> [u8; HEADER_LEN + RECORD_LEN] {
+        let mut table = [0x0u8; HEADER_LEN + RECORD_LEN];
+        table[..HEADER_LEN].copy_from_slice(&header(TableMode::Bisect, count));
+        table[HEADER_LEN..].copy_from_slice(&CHECKPOINT.to_bytes());
+        table
+    }
+
+    #[test]
+    fn checkpoint_round_trips() {
+        let bytes = CHECKPOINT.to_bytes();
+        assert_eq!(
+            bytes,
+            [
+                0x03, 0x02, 0x01, 0x00, 0x05, 0x04, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x4C,
+                0xF5, 0xC5
+            ]
+        );
+        assert_eq!(Checkpoint::from_bytes(&bytes), CHECKPOINT);
+        let table = table(1);
+        let table = CheckpointTable::parse(&table).unwrap();
+        assert_eq!(table.mode(), TableMode::Bisect);
+        assert_eq!(table.get(0), Some(CHECKPOINT));
+        assert_eq!(table.get(1), None);
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        let mut table = table(1);
+        table[3] = b'D';
+        assert_eq!(
+            CheckpointTable::parse(&table).unwrap_err(),
+            TableError::Magic
+        );
+    }
+
+    #[test]
+    fn rejects_bad_version() {
+        let mut table = table(1);
+        table[4] = VERSION + 1;
+        assert_eq!(
+            CheckpointTable::parse(&table).unwrap_err(),
+            TableError::Version(VERSION + 1)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_mode() {
+        let mut table = table(1);
+        table[5] = 2;
+        assert_eq!(
+            CheckpointTable::parse(&table).unwrap_err(),
+            TableError::Mode(2)
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_length() {
+        let table = table(1);
+        assert_eq!(
+            CheckpointTable::parse(&table[..HEADER_LEN - 1]).unwrap_err(),
+            TableError::Length {
+                expected: HEADER_LEN,
+                actual: HEADER_LEN - 1
+            }
+        );
+        assert_eq!(
+            CheckpointTable::parse(&table[..table.len() - 1]).unwrap_err(),
+            TableError::Length {
+                expected: table.len(),
+                actual: table.len() - 1
+            }
+        );
+        // a header that counts more records than follow it.
+        let table = self::table(2);
+        assert_eq!(
+            CheckpointTable::parse(&table).unwrap_err(),
+            TableError::Length {
+                expected: HEADER_LEN + 2 * RECORD_LEN,
+                actual: table.len()
+            }
+        );
+    }
+}
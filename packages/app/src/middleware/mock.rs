@@ -0,0 +1,254 @@
+//! Answers the memory requests of `CpuMode::DebugWithinMockMemory` from a 64KiB image, so a
+//! whole program can run in mock mode and be checked by the memory it leaves behind.
+//!
+//! The FPGA sends `[addr_low, addr_high]` for a read and waits for the data, and
+//! `[addr_low, addr_high, data]` for a write. Nothing in those bytes tells the two apart, so
+//! the reference cpu runs alongside the FPGA and says which one comes next.
+
+use crate::cpu::bus::Bus;
+use crate::cpu::Cpu;
+use crate::middleware::check::{CheckError, CheckKind, CheckResult};
+use crate::middleware::checkpoint::SEQUENCE_BATCH;
+use crate::middleware::mode::command::Command;
+use crate::middleware::state::CpuState;
+use crate::middleware::transport::Transport;
+use crate::middleware::util::send_command;
+
+/// Writes kept by [`MockMemory::writes`]. Later ones still land in memory.
+pub const WRITE_LOG_LEN: usize = 64;
+
+/// Differences [`MockMemory::check_memory`] logs before it gives up listing them.
+const LOGGED_DIFFS: usize = 8;
+
+/// Memory behind the FPGA in mock mode, plain 64KiB without any mirroring.
+///
+/// Commands have to go through [`MockMemory::send_command`] so that it can follow the cpu.
+pub struct MockMemory<'a> {
+    memory: &'a mut [u8; 0x10000],
+    cpu: Cpu,
+    writes: [(u16, u8); WRITE_LOG_LEN],
+    write_count: usize,
+}
+
+impl<'a> MockMemory<'a> {
+    pub fn new(memory: &'a mut [u8; 0x10000]) -> Self {
+        Self {
+            memory,
+            cpu: Cpu::new(),
+            writes: [(0, 0); WRITE_LOG_LEN],
+            write_count: 0,
+        }
+    }
+
+    /// Copies `bytes` from `addr` on, wrapping at $FFFF.
+    pub fn load(&mut self, addr: u16, bytes: &[u8]) {
+        for (i, byte) in bytes.iter().enumerate() {
+            self.memory[addr.wrapping_add(i as u16) as usize] = *byte;
+        }
+    }
+
+    pub fn memory(&self) -> &[u8; 0x10000] {
+        self.memory
+    }
+
+    pub fn memory_mut(&mut self) -> &mut [u8; 0x10000] {
+        self.memory
+    }
+
+    /// Registers of the reference cpu, which the FPGA should hold as well.
+    pub fn state(&self) -> CpuState {
+        self.cpu.registers
+    }
+
+    /// First [`WRITE_LOG_LEN`] writes since the last [`MockMemory::clear_writes`], as
+    /// `(addr, data)` in the order the FPGA sent them.
+    pub fn writes(&self) -> &[(u16, u8)] {
+        &self.writes[..self.write_count.min(WRITE_LOG_LEN)]
+    }
+
+    /// Every write since the last [`MockMemory::clear_writes`], including the ones not kept.
+    pub fn write_count(&self) -> usize {
+        self.write_count
+    }
+
+    pub fn clear_writes(&mut self) {
+        self.write_count = 0;
+    }
+
+    /// Sends `command` and serves every memory request it causes.
+    ///
+    /// `CpuMode` resets the reference cpu, which assumes the FPGA was reset before it.
+    pub fn send_command<T: Transport>(
+        &mut self,
+        transport: &mut T,
+        command: Command,
+    ) -> CheckResult<T> {
+        send_command(transport, command)?;
+        match command {
+            Command::CpuMode(_) => self.cpu.reset(),
+            Command::Inst { opcode, operands } => self.step(transport, Some((opcode, operands)))?,
+            Command::Sequence(count) => {
+                for _ in 0..count {
+                    self.step(transport, None)?;
+                }
+            }
+            Command::CassetteMode(_) | Command::RegisterTransfer(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Runs `count` instructions from memory, split into `OpeMode::Sequence` frames.
+    pub fn run<T: Transport>(&mut self, transport: &mut T, mut count: u32) -> CheckResult<T> {
+        while count > 0 {
+            let batch = count.min(SEQUENCE_BATCH as u32);
+            self.send_command(transport, Command::Sequence(batch as u8))?;
+            count -= batch;
+        }
+        Ok(())
+    }
+
+    /// Addresses whose byte differs from `expected`, as `(addr, expected, actual)`.
+    pub fn diff<'e>(
+        &'e self,
+        expected: &'e [u8; 0x10000],
+    ) -> impl Iterator<Item = (u16, u8, u8)> + 'e {
+        expected
+            .iter()
+            .zip(self.memory.iter())
+            .enumerate()
+            .filter(|(_, (expected, actual))| expected != actual)
+            .map(|(addr, (expected, actual))| (addr as u16, *expected, *actual))
+    }
+
+    /// Compares memory with `expected` and fails at the lowest address that differs.
+    pub fn check_memory<E>(&self, expected: &[u8; 0x10000]) -> Result<(), CheckError<E>> {
+        let mut diffs = self.diff(expected);
+        let Some((addr, expected_data, actual_data)) = diffs.next() else {
+            info!("valid memory.");
+            return Ok(());
+        };
+        info!(
+            "memory differs at {:04X}. expect value is {:X}, but memory has {:X}",
+            addr, expected_data, actual_data
+        );
+        let mut count = 1;
+        for (addr, expected_data, actual_data) in diffs {
+            if count < LOGGED_DIFFS {
+                info!(
+                    "memory differs at {:04X}. expect value is {:X}, but memory has {:X}",
+                    addr, expected_data, actual_data
+                );
+            }
+            count += 1;
+        }
+        info!("{} bytes differ.", count);
+        Err(CheckError::new(
+            CheckKind::Memory(addr),
+            &[expected_data],
+            &[actual_data],
+        ))
+    }
+
+    fn step<T: Transport>(
+        &mut self,
+        transport: &mut T,
+        inst: Option<(u8, &[u8])>,
+    ) -> CheckResult<T> {
+        let mut cpu = self.cpu;
+        let mut responder = Responder {
+            mock: self,
+            transport,
+            inst,
+            fetched: 0,
+            error: None,
+        };
+        cpu.step(&mut responder);
+        match responder.error {
+            Some(error) => Err(error),
+            None => {
+                self.cpu = cpu;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Bus of the reference cpu for one instruction, answering the FPGA as it goes.
+///
+/// `Bus` cannot fail, so the first error is kept and everything after it is skipped.
+struct Responder<'m, 'a, 't, 'i, T: Transport> {
+    mock: &'m mut MockMemory<'a>,
+    transport: &'t mut T,
+    /// Opcode and operands of `OpeMode::Inst`, which the FPGA does not ask for.
+    inst: Option<(u8, &'i [u8])>,
+    fetched: usize,
+    error: Option<CheckError<T::Error>>,
+}
+
+impl<T: Transport> Responder<'_, '_, '_, '_, T> {
+    /// Reads the request for `addr` and returns the address the FPGA sent.
+    fn receive(&mut self, addr: u16, request: &mut [u8]) -> Option<u16> {
+        if self.error.is_some() {
+            return None;
+        }
+        if let Err(error) = self.transport.read(request) {
+            self.error = Some(CheckError::transport(error));
+            return None;
+        }
+        let requested = u16::from_le_bytes([request[0], request[1]]);
+        if requested != addr {
+            info!(
+                "test failed. expect access to {:04X}, but the fpga sent {:X}",
+                addr, request
+            );
+            let [low, high] = addr.to_le_bytes();
+            let expected = [low, high, request.get(2).copied().unwrap_or_default()];
+            self.error = Some(CheckError::new(
+                CheckKind::Read,
+                &expected[..request.len()],
+                request,
+            ));
+        }
+        Some(requested)
+    }
+}
+
+impl<T: Transport> Bus for Responder<'_, '_, '_, '_, T> {
+    fn read(&mut self, addr: u16) -> u8 {
+        let Some(requested) = self.receive(addr, &mut [0x0u8; 2]) else {
+            return 0;
+        };
+        let data = self.mock.memory[requested as usize];
+        if let Err(error) = self.transport.write(&[data]) {
+            self.error.get_or_insert(CheckError::transport(error));
+        }
+        info!("mock read {:04X} -> {:X}", requested, data);
+        data
+    }
+
+    fn write(&mut self, addr: u16, _data: u8) {
+        let mut request = [0x0u8; 3];
+        let Some(requested) = self.receive(addr, &mut request) else {
+            return;
+        };
+        let data = request[2];
+        self.mock.memory[requested as usize] = data;
+        if let Some(entry) = self.mock.writes.get_mut(self.mock.write_count) {
+            *entry = (requested, data);
+        }
+        self.mock.write_count += 1;
+        info!("mock write {:04X} <- {:X}", requested, data);
+    }
+
+    fn fetch(&mut self, addr: u16) -> u8 {
+        let Some((opcode, operands)) = self.inst else {
+            return self.read(addr);
+        };
+        let data = match self.fetched {
+            0 => opcode,
+            n => operands.get(n - 1).copied().unwrap_or_default(),
+        };
+        self.fetched += 1;
+        data
+    }
+}
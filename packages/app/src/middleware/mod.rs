@@ -1 +1,8 @@
+pub mod check;
+pub mod checkpoint;
+pub mod mock;
 pub mod mode;
+pub mod runner;
+pub mod state;
+pub mod transport;
+pub mod util;
@@ -0,0 +1,256 @@
+use crate::middleware::mode::{CassetteMode, CpuMode, OpeMode, TxReg, UnknownValue};
+use crate::operators::OPERATORS;
+
+/// Longest frame, `[OpeMode::Inst, opcode, operand_low, operand_high]`.
+pub const MAX_FRAME_LEN: usize = 4;
+
+/// Something the stm32 sends to the FPGA debug module.
+///
+/// After reset the FPGA expects `CpuMode` then `CassetteMode`, and every frame after that
+/// starts with an `OpeMode`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command<'a> {
+    CpuMode(CpuMode),
+    CassetteMode(CassetteMode),
+    /// Runs one instruction. Opcode and operands come over usart instead of memory.
+    Inst {
+        opcode: u8,
+        operands: &'a [u8],
+    },
+    /// Runs the given number of instructions fetched from memory.
+    Sequence(u8),
+    /// Asks for a register, which the FPGA replies with [`TxReg::reply_len`] bytes.
+    RegisterTransfer(TxReg),
+}
+
+/// Frame the FPGA would not read the way the sender meant.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameError {
+    UnknownValue(u8),
+    /// The FPGA reads as many operands as the addressing mode of `opcode` needs, so any
+    /// other count leaves it waiting or eats the next frame.
+    OperandLength {
+        opcode: u8,
+        expected: usize,
+        actual: usize,
+    },
+    /// Decoding ran out of bytes before the frame ended.
+    Incomplete {
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl From<UnknownValue> for FrameError {
+    fn from(value: UnknownValue) -> Self {
+        FrameError::UnknownValue(value.0)
+    }
+}
+
+/// Encoded bytes of a single [`Command`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Frame {
+    buf: [u8; MAX_FRAME_LEN],
+    len: usize,
+}
+
+impl Frame {
+    fn new(data: &[u8]) -> Self {
+        let mut buf = [0x0u8; MAX_FRAME_LEN];
+        buf[..data.len()].copy_from_slice(data);
+        Self {
+            buf,
+            len: data.len(),
+        }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+/// Operand bytes the FPGA reads after `opcode` in `OpeMode::Inst`.
+pub const fn operand_len(opcode: u8) -> usize {
+    OPERATORS[opcode as usize].addr_mode.operand_len()
+}
+
+/// Checks `operands` against [`operand_len`] and returns their length.
+const fn check_operand_len(opcode: u8, operands: &[u8]) -> Result<usize, FrameError> {
+    let expected = operand_len(opcode);
+    if operands.len() != expected {
+        return Err(FrameError::OperandLength {
+            opcode,
+            expected,
+            actual: operands.len(),
+        });
+    }
+    Ok(expected)
+}
+
+impl<'a> Command<'a> {
+    /// `OpeMode::Inst` frame whose operand count is checked against the opcode.
+    ///
+    /// Fails with [`FrameError::OperandLength`], which `?` turns into a failed check.
+    pub const fn inst(opcode: u8, operands: &'a [u8]) -> Result<Self, FrameError> {
+        match check_operand_len(opcode, operands) {
+            Ok(_) => Ok(Command::Inst { opcode, operands }),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn encode(&self) -> Result<Frame, FrameError> {
+        let frame = match *self {
+            Command::CpuMode(cpu_mode) => Frame::new(&[cpu_mode as u8]),
+            Command::CassetteMode(cassette_mode) => Frame::new(&[cassette_mode as u8]),
+            Command::Inst { opcode, operands } => {
+                // `Command::Inst` can also be built without `Command::inst`.
+                let expected = check_operand_len(opcode, operands)?;
+                let mut frame = Frame::new(&[OpeMode::Inst as u8, opcode]);
+                frame.buf[2..2 + expected].copy_from_slice(operands);
+                frame.len += expected;
+                frame
+            }
+            Command::Sequence(count) => Frame::new(&[OpeMode::Sequence as u8, count]),
+            Command::RegisterTransfer(register) => {
+                Frame::new(&[OpeMode::RegisterTransfer as u8, register as u8])
+            }
+        };
+        Ok(frame)
+    }
+
+    /// Decodes the frame at the head of `bytes` and returns it with its length.
+    ///
+    /// Only `OpeMode` frames are decoded here. `CpuMode` and `CassetteMode` share byte values
+    /// with them, so the two bytes after reset are read with `TryFrom<u8>` instead.
+    pub fn decode(bytes: &'a [u8]) -> Result<(Self, usize), FrameError> {
+        let frame_len = |expected: usize| {
+            if bytes.len() < expected {
+                Err(FrameError::Incomplete {
+                    expected,
+                    actual: bytes.len(),
+                })
+            } else {
+                Ok(expected)
+            }
+        };
+        let Some(&head) = bytes.first() else {
+            return Err(FrameError::Incomplete {
+                expected: 1,
+                actual: 0,
+            });
+        };
+        match OpeMode::try_from(head)? {
+            OpeMode::Inst => {
+                let opcode = bytes[frame_len(2)? - 1];
+                let len = frame_len(2 + operand_len(opcode))?;
+                let operands = &bytes[2..len];
+                Ok((Command::Inst { opcode, operands }, len))
+            }
+            OpeMode::Sequence => {
+                let len = frame_len(2)?;
+                Ok((Command::Sequence(bytes[1]), len))
+            }
+            OpeMode::RegisterTransfer => {
+                let len = frame_len(2)?;
+                Ok((Command::RegisterTransfer(TxReg::try_from(bytes[1])?), len))
+            }
+        }
+    }
+
+    /// Bytes the FPGA sends back once the frame is read, apart from mocked memory accesses.
+    pub const fn reply_len(&self) -> usize {
+        match self {
+            Command::RegisterTransfer(register) => register.reply_len(),
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(command: Command, bytes: &[u8]) {
+        let frame = command.encode().unwrap();
+        assert_eq!(frame.as_slice(), bytes, "{:?}", command);
+        assert_eq!(Command::decode(bytes), Ok((command, bytes.len())));
+    }
+
+    #[test]
+    fn inst_round_trips_every_operand_len() {
+        // BRK, LDA #$10, JMP $C000
+        round_trip(Command::inst(0x00, &[]).unwrap(), &[0x00, 0x00]);
+        round_trip(Command::inst(0xA9, &[0x10]).unwrap(), &[0x00, 0xA9, 0x10]);
+        round_trip(
+            Command::inst(0x4C, &[0x00, 0xC0]).unwrap(),
+            &[0x00, 0x4C, 0x00, 0xC0],
+        );
+    }
+
+    #[test]
+    fn sequence_and_register_transfer_round_trip() {
+        round_trip(Command::Sequence(0xFF), &[0x01, 0xFF]);
+        for register in [TxReg::A, TxReg::X, TxReg::Y, TxReg::S, TxReg::P, TxReg::PC] {
+            round_trip(Command::RegisterTransfer(register), &[0x02, register as u8]);
+        }
+    }
+
+    #[test]
+    fn modes_round_trip_through_try_from() {
+        for mode in [OpeMode::Inst, OpeMode::Sequence, OpeMode::RegisterTransfer] {
+            assert_eq!(OpeMode::try_from(mode as u8), Ok(mode));
+        }
+        assert_eq!(OpeMode::try_from(3), Err(UnknownValue(3)));
+        for mode in [
+            CpuMode::Nop,
+            CpuMode::Normal,
+            CpuMode::DebugWithinMockMemory,
+            CpuMode::DebugWithinInternalMemory,
+        ] {
+            let frame = Command::CpuMode(mode).encode().unwrap();
+            assert_eq!(CpuMode::try_from(frame.as_slice()[0]), Ok(mode));
+        }
+        for mode in [CassetteMode::Nop, CassetteMode::None, CassetteMode::NesTest] {
+            let frame = Command::CassetteMode(mode).encode().unwrap();
+            assert_eq!(CassetteMode::try_from(frame.as_slice()[0]), Ok(mode));
+        }
+    }
+
+    #[test]
+    fn operand_len_is_checked_once_for_both_ways_in() {
+        let error = FrameError::OperandLength {
+            opcode: 0x4C,
+            expected: 2,
+            actual: 1,
+        };
+        assert_eq!(Command::inst(0x4C, &[0x00]), Err(error));
+        let command = Command::Inst {
+            opcode: 0x4C,
+            operands: &[0x00],
+        };
+        assert_eq!(command.encode(), Err(error));
+    }
+
+    #[test]
+    fn decode_rejects_short_and_unknown_frames() {
+        let incomplete = |expected, actual| FrameError::Incomplete { expected, actual };
+        assert_eq!(Command::decode(&[]), Err(incomplete(1, 0)));
+        assert_eq!(Command::decode(&[0x00]), Err(incomplete(2, 1)));
+        assert_eq!(Command::decode(&[0x00, 0x4C, 0x00]), Err(incomplete(4, 3)));
+        assert_eq!(Command::decode(&[0x01]), Err(incomplete(2, 1)));
+        assert_eq!(
+            Command::decode(&[0x03]),
+            Err(FrameError::UnknownValue(0x03))
+        );
+        assert_eq!(
+            Command::decode(&[0x02, 0x06]),
+            Err(FrameError::UnknownValue(0x06))
+        );
+        // trailing bytes belong to the next frame.
+        assert_eq!(
+            Command::decode(&[0x01, 0x02, 0x00]),
+            Ok((Command::Sequence(0x02), 2))
+        );
+    }
+}
@@ -1,4 +1,7 @@
-#[derive(Clone, Copy, Debug)]
+pub mod command;
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CpuMode {
     Nop,
     Normal,
@@ -6,21 +9,24 @@ pub enum CpuMode {
     DebugWithinInternalMemory,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OpeMode {
     Inst,
     Sequence,
     RegisterTransfer,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CassetteMode {
     Nop,
     None,
     NesTest,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TxReg {
     A,
     X,
@@ -30,7 +36,8 @@ pub enum TxReg {
     PC,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AddrMode {
     Acc,
     Imm,
@@ -47,3 +54,83 @@ pub enum AddrMode {
     Ind,
     Nop,
 }
+
+impl TxReg {
+    /// Bytes the FPGA replies to `OpeMode::RegisterTransfer`. PC is sent low byte first.
+    pub const fn reply_len(self) -> usize {
+        match self {
+            TxReg::PC => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl AddrMode {
+    /// Operand bytes following the opcode.
+    pub const fn operand_len(self) -> usize {
+        match self {
+            AddrMode::Acc | AddrMode::Impl | AddrMode::Nop => 0,
+            AddrMode::Imm
+            | AddrMode::Zp
+            | AddrMode::ZpX
+            | AddrMode::ZpY
+            | AddrMode::Rel
+            | AddrMode::IndX
+            | AddrMode::IndY => 1,
+            AddrMode::Abs | AddrMode::AbsX | AddrMode::AbsY | AddrMode::Ind => 2,
+        }
+    }
+}
+
+/// Byte that does not encode any variant of the target enum.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownValue(pub u8);
+
+// variants are listed in declaration order, which is the encoding the FPGA uses.
+macro_rules! try_from_u8 {
+    ($ty:ident { $($variant:ident),* $(,)? }) => {
+        impl TryFrom<u8> for $ty {
+            type Error = UnknownValue;
+
+            fn try_from(value: u8) -> Result<Self, UnknownValue> {
+                $(
+                    if value == $ty::$variant as u8 {
+                        return Ok($ty::$variant);
+                    }
+                )*
+                Err(UnknownValue(value))
+            }
+        }
+    };
+}
+
+try_from_u8!(CpuMode {
+    Nop,
+    Normal,
+    DebugWithinMockMemory,
+    DebugWithinInternalMemory,
+});
+try_from_u8!(OpeMode {
+    Inst,
+    Sequence,
+    RegisterTransfer,
+});
+try_from_u8!(CassetteMode { Nop, None, NesTest });
+try_from_u8!(TxReg { A, X, Y, S, P, PC });
+try_from_u8!(AddrMode {
+    Acc,
+    Imm,
+    Abs,
+    AbsX,
+    AbsY,
+    Zp,
+    ZpX,
+    ZpY,
+    Impl,
+    Rel,
+    IndX,
+    IndY,
+    Ind,
+    Nop,
+});
@@ -0,0 +1,129 @@
+use crate::middleware::check::CheckResult;
+use crate::middleware::transport::{ControlPins, Transport};
+
+/// Named group of tests registered in a firmware image.
+pub struct Suite<D> {
+    pub name: &'static str,
+    pub run: fn(&mut TestRunner, &mut D),
+}
+
+impl<D> Suite<D> {
+    pub const fn new(name: &'static str, run: fn(&mut TestRunner, &mut D)) -> Self {
+        Self { name, run }
+    }
+}
+
+/// Runs tests one after another and keeps going after a failure.
+///
+/// Every test ends with `test <name> passed.` or `test <name> failed.`, and every suite is
+/// wrapped in `suite <name> started.` and `suite <name> passed.` or `failed.`, which
+/// test-executor reads to build its reports.
+#[derive(Default)]
+pub struct TestRunner {
+    passed: usize,
+    failed: usize,
+}
+
+impl TestRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `test`, and on failure records it and resets the FPGA for the next test.
+    /// Returns whether the test passed.
+    pub fn run<D: Transport + ControlPins>(
+        &mut self,
+        fc2: &mut D,
+        test_name: &'static str,
+        test: impl FnOnce(&mut D) -> CheckResult<D>,
+    ) -> bool {
+        match test(fc2) {
+            Ok(()) => {
+                self.passed += 1;
+                info!("test {} passed.", test_name);
+                true
+            }
+            Err(mut error) => {
+                error.test_name = test_name;
+                info!("{}", error);
+                info!("test {} failed.", test_name);
+                self.failed += 1;
+                fc2.set_resb(true);
+                fc2.set_resb(false);
+                info!("fpga reset after {}.", test_name);
+                false
+            }
+        }
+    }
+
+    /// Runs the suites named in `filter`, a comma separated list, or every suite when it is
+    /// `None`. An unknown name counts as a failure.
+    pub fn run_suites<D: Transport + ControlPins>(
+        &mut self,
+        fc2: &mut D,
+        suites: &[Suite<D>],
+        filter: Option<&str>,
+    ) {
+        match filter {
+            None => {
+                for suite in suites {
+                    self.run_suite(fc2, suite);
+                }
+            }
+            Some(filter) => {
+                for name in filter
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                {
+                    match suites.iter().find(|suite| suite.name == name) {
+                        Some(suite) => self.run_suite(fc2, suite),
+                        None => {
+                            info!("unknown suite {}.", name);
+                            info!("suite {} failed.", name);
+                            self.failed += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn run_suite<D: Transport + ControlPins>(&mut self, fc2: &mut D, suite: &Suite<D>) {
+        info!("suite {} started.", suite.name);
+        let failed = self.failed;
+        (suite.run)(self, fc2);
+        match self.failed == failed {
+            true => info!("suite {} passed.", suite.name),
+            false => info!("suite {} failed.", suite.name),
+        }
+    }
+
+    pub fn passed(&self) -> usize {
+        self.passed
+    }
+
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Logs the totals and ends with "all tests passed!", which test-executor waits for.
+    /// Panics otherwise so that probe-run exits instead of waiting for the timeout.
+    pub fn finish(&self) {
+        info!("{} passed, {} failed.", self.passed, self.failed);
+        if self.failed > 0 {
+            panic!("{} tests failed", self.failed);
+        }
+        info!("all tests passed!");
+    }
+}
+
+/// Runs a test through a [`TestRunner`] under its own function name.
+///
+/// `run_test!(runner, &mut fc2, test_lda_imm_within_internal_memory);`
+#[macro_export]
+macro_rules! run_test {
+    ($runner:expr, $fc2:expr, $test:ident) => {
+        $runner.run($fc2, stringify!($test), $test)
+    };
+}
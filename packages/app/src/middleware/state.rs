@@ -0,0 +1,143 @@
+//! Snapshots of every register the FPGA can transfer, compared against an expected one in a
+//! single check.
+
+use crate::cpu::{FlagDiff, Registers, StatusFlags};
+use crate::middleware::check::{CheckError, CheckKind, CheckResult};
+use crate::middleware::mode::TxReg;
+use crate::middleware::transport::Transport;
+use crate::middleware::util::read_register;
+use core::fmt;
+
+/// Registers read back from the FPGA, or from `DebugSimulator` on the host, at one point.
+pub type CpuState = Registers;
+
+/// Order in which a snapshot is read and its differences are listed.
+pub const REGISTERS: [TxReg; 6] = [TxReg::A, TxReg::X, TxReg::Y, TxReg::P, TxReg::S, TxReg::PC];
+
+/// Value of `register` in `state`.
+pub fn register(state: &CpuState, register: TxReg) -> u16 {
+    match register {
+        TxReg::A => state.a as u16,
+        TxReg::X => state.x as u16,
+        TxReg::Y => state.y as u16,
+        TxReg::S => state.s as u16,
+        TxReg::P => state.p as u16,
+        TxReg::PC => state.pc,
+    }
+}
+
+/// Reads every register. The protocol transfers one register per frame, so this still sends
+/// one `OpeMode::RegisterTransfer` for each.
+pub fn read_state<T: Transport>(transport: &mut T) -> Result<CpuState, CheckError<T::Error>> {
+    Ok(CpuState {
+        a: read_register(transport, TxReg::A)? as u8,
+        x: read_register(transport, TxReg::X)? as u8,
+        y: read_register(transport, TxReg::Y)? as u8,
+        p: read_register(transport, TxReg::P)? as u8,
+        s: read_register(transport, TxReg::S)? as u8,
+        pc: read_register(transport, TxReg::PC)?,
+    })
+}
+
+/// Reads every register and fails with all the differences from `expect` at once.
+pub fn check_state<T: Transport>(transport: &mut T, expect: &CpuState) -> CheckResult<T> {
+    let diff = StateDiff::new(*expect, read_state(transport)?);
+    if diff.is_empty() {
+        info!("valid cpu state.");
+        Ok(())
+    } else {
+        info!("cpu state differs. {}", diff);
+        Err(CheckError::new(CheckKind::State(diff), &[], &[]))
+    }
+}
+
+/// Expected and actual snapshot, written as
+/// `A: expected 4C, got 00; P: expected N set, got N clear` for the registers that differ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StateDiff {
+    pub expected: CpuState,
+    pub actual: CpuState,
+}
+
+impl StateDiff {
+    pub fn new(expected: CpuState, actual: CpuState) -> Self {
+        Self { expected, actual }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expected == self.actual
+    }
+
+    /// Registers that differ, in [`REGISTERS`] order.
+    pub fn registers(&self) -> impl Iterator<Item = TxReg> + '_ {
+        REGISTERS
+            .into_iter()
+            .filter(|&reg| register(&self.expected, reg) != register(&self.actual, reg))
+    }
+
+    fn flags(&self) -> FlagDiff {
+        StatusFlags::from(self.expected.p).diff(StatusFlags::from(self.actual.p))
+    }
+}
+
+impl fmt::Display for StateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, reg) in self.registers().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            let (expected, actual) = (register(&self.expected, reg), register(&self.actual, reg));
+            match reg {
+                TxReg::P => write!(f, "P: {}", self.flags())?,
+                TxReg::PC => write!(f, "PC: expected {:04X}, got {:04X}", expected, actual)?,
+                _ => write!(
+                    f,
+                    "{}: expected {:02X}, got {:02X}",
+                    name(reg),
+                    expected,
+                    actual
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for StateDiff {
+    fn format(&self, f: defmt::Formatter) {
+        for (i, reg) in self.registers().enumerate() {
+            if i > 0 {
+                defmt::write!(f, "; ");
+            }
+            let (expected, actual) = (register(&self.expected, reg), register(&self.actual, reg));
+            match reg {
+                TxReg::P => defmt::write!(f, "P: {}", self.flags()),
+                TxReg::PC => defmt::write!(
+                    f,
+                    "PC: expected {=u16:04X}, got {=u16:04X}",
+                    expected,
+                    actual
+                ),
+                _ => defmt::write!(
+                    f,
+                    "{=str}: expected {=u16:02X}, got {=u16:02X}",
+                    name(reg),
+                    expected,
+                    actual
+                ),
+            }
+        }
+    }
+}
+
+fn name(register: TxReg) -> &'static str {
+    match register {
+        TxReg::A => "A",
+        TxReg::X => "X",
+        TxReg::Y => "Y",
+        TxReg::S => "S",
+        TxReg::P => "P",
+        TxReg::PC => "PC",
+    }
+}
@@ -0,0 +1,25 @@
+/// Byte stream between the test host and the FPGA debug usart.
+///
+/// Implemented by the embassy usart on stm32, and by anything else that can stand in for
+/// the FPGA such as a host serial port or `debug_simulator`.
+pub trait Transport {
+    type Error: core::fmt::Debug;
+
+    /// Sends every byte of `data`.
+    fn write(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Fills the whole of `buf`.
+    fn read(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Control lines of the FPGA cpu.
+pub trait ControlPins {
+    /// Level of the RW pin. High means read.
+    fn is_rw_high(&mut self) -> bool;
+
+    /// Level of the Nop pin. High while cpu mode is nop.
+    fn is_nop_high(&mut self) -> bool;
+
+    /// Drives the RESB pin.
+    fn set_resb(&mut self, high: bool);
+}
@@ -0,0 +1,154 @@
+use crate::cpu::StatusFlags;
+use crate::disasm::Instruction;
+use crate::middleware::check::{CheckError, CheckKind, CheckResult};
+use crate::middleware::mode::command::{Command, Frame};
+use crate::middleware::mode::{OpeMode, TxReg};
+use crate::middleware::transport::{ControlPins, Transport};
+
+/// Asks for `register` with `OpeMode::RegisterTransfer` and returns its reply.
+pub fn read_register<T: Transport>(
+    transport: &mut T,
+    register: TxReg,
+) -> Result<u16, CheckError<T::Error>> {
+    send_command(transport, Command::RegisterTransfer(register))?;
+    info!("write tx reg.");
+    let mut read_buf = [0x0u8; 2];
+    transport
+        .read(&mut read_buf[..register.reply_len()])
+        .map_err(CheckError::transport)?;
+    Ok(u16::from_le_bytes(read_buf))
+}
+
+pub fn check_valid_register_status<T: Transport>(
+    transport: &mut T,
+    register: TxReg,
+    expect_data: &[u8],
+) -> CheckResult<T> {
+    let value = read_register(transport, register)?.to_le_bytes();
+    let read_buf = &value[..register.reply_len()];
+    if read_buf == expect_data {
+        info!("valid register status.");
+        Ok(())
+    } else {
+        info!(
+            "test failed. expect value is {:X}, but return value is {:X}",
+            expect_data, read_buf
+        );
+        Err(CheckError::new(
+            CheckKind::Register(register),
+            expect_data,
+            read_buf,
+        ))
+    }
+}
+
+/// Checks the P register, whose mismatch is logged flag by flag.
+pub fn check_status_flags<T: Transport>(transport: &mut T, expect: StatusFlags) -> CheckResult<T> {
+    check_valid_register_status(transport, TxReg::P, &[expect.bits()])
+}
+
+pub fn usart_write<T: Transport>(transport: &mut T, send_data: &[u8]) -> CheckResult<T> {
+    for n in send_data {
+        transport.write(&[*n]).map_err(CheckError::transport)?;
+        info!("usart write data({:X})", n);
+    }
+    Ok(())
+}
+
+/// Encodes `command` and sends it, or sends nothing if the frame is malformed.
+pub fn send_command<T: Transport>(transport: &mut T, command: Command) -> CheckResult<T> {
+    let frame = encode(command)?;
+    log_inst(&frame);
+    usart_write(transport, frame.as_slice())
+}
+
+/// Sends `command` but holds back everything after the opcode until `check` passes, so a
+/// test can look at the pins while the FPGA waits for the operands.
+pub fn send_command_with_check<T: Transport>(
+    transport: &mut T,
+    command: Command,
+    check: impl FnOnce(&mut T) -> CheckResult<T>,
+) -> CheckResult<T> {
+    let frame = encode(command)?;
+    log_inst(&frame);
+    let frame = frame.as_slice();
+    let (head, operands) = frame.split_at(frame.len().min(2));
+    usart_write(transport, head)?;
+    check(transport)?;
+    usart_write(transport, operands)
+}
+
+/// Names the instruction of an `OpeMode::Inst` frame, which gives a failing check its context.
+fn log_inst(frame: &Frame) {
+    if let [mode, inst @ ..] = frame.as_slice() {
+        if *mode == OpeMode::Inst as u8 {
+            if let Some(instruction) = Instruction::decode(inst) {
+                info!("inst {}", instruction);
+            }
+        }
+    }
+}
+
+fn encode<E>(command: Command) -> Result<Frame, CheckError<E>> {
+    Ok(command.encode()?)
+}
+
+pub fn usart_read_with_check<T: Transport>(
+    transport: &mut T,
+    read_buf: &mut [u8],
+    expect_data: &[u8],
+) -> CheckResult<T> {
+    transport.read(read_buf).map_err(CheckError::transport)?;
+    if read_buf == expect_data {
+        info!("6502 receive expece data.");
+        Ok(())
+    } else {
+        info!(
+            "test failed. expect value is {:X}, but return value is {:X}",
+            expect_data, read_buf
+        );
+        Err(CheckError::new(CheckKind::Read, expect_data, read_buf))
+    }
+}
+
+pub fn send_reset_signal_if_not_nop<D: Transport + ControlPins>(fc2: &mut D) -> CheckResult<D> {
+    if !fc2.is_nop_high() {
+        fc2.set_resb(true);
+        info!("send reset signal.");
+        fc2.set_resb(false);
+        match fc2.is_nop_high() {
+            true => info!("fpga reset!"),
+            false => {
+                info!("failed to reset fpga.");
+                return Err(CheckError::level(CheckKind::Reset, true));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn check_rw_is_high<D: Transport + ControlPins>(fc2: &mut D) -> CheckResult<D> {
+    match fc2.is_rw_high() {
+        true => {
+            info!("rw flag is high");
+            Ok(())
+        }
+        false => {
+            info!("test failed. rw flag is not high.");
+            Err(CheckError::level(CheckKind::Rw, true))
+        }
+    }
+}
+
+pub fn check_rw_is_low<D: Transport + ControlPins>(fc2: &mut D) -> CheckResult<D> {
+    match fc2.is_rw_high() {
+        false => {
+            info!("rw flag is low");
+            Ok(())
+        }
+        true => {
+            info!("test failed. rw flag is not low.");
+            Err(CheckError::level(CheckKind::Rw, false))
+        }
+    }
+}
@@ -0,0 +1,505 @@
+use crate::middleware::mode::AddrMode;
+
+/// Operation kinds decoded by the FPGA, at the same position as in `OperatorsPkg::OpeKind`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpeKind {
+    Adc,
+    Sbc,
+    And,
+    Ora,
+    Eor,
+    Asl,
+    Lsr,
+    Rol,
+    Ror,
+    Bcc,
+    Bcs,
+    Beq,
+    Bne,
+    Bvc,
+    Bvs,
+    Bpl,
+    Bmi,
+    Bit,
+    Jmp,
+    Jsr,
+    Rts,
+    Brk,
+    Rti,
+    Cmp,
+    Cpx,
+    Cpy,
+    Inc,
+    Dec,
+    Inx,
+    Dex,
+    Iny,
+    Dey,
+    Clc,
+    Sec,
+    Cli,
+    Sei,
+    Cld,
+    Sed,
+    Clv,
+    Lda,
+    Ldx,
+    Ldy,
+    Sta,
+    Stx,
+    Sty,
+    Tax,
+    Txa,
+    Tay,
+    Tya,
+    Tsx,
+    Txs,
+    Pha,
+    Pla,
+    Php,
+    Plp,
+    Nop,
+    Dop,
+    Kil,
+    Slo,
+    Aac,
+    Atx,
+    Top,
+    Rla,
+    Sre,
+    Dcp,
+    Asr,
+    Rra,
+    Arr,
+    Sax,
+    Xaa,
+    Axa,
+    Xas,
+    Sxa,
+    Sya,
+    Lax,
+    Lxa,
+    // `Lar` of operators.veryl comes here. No opcode decodes to it, so it is left out and
+    // `Las` keeps the position the FPGA gives it.
+    Las = 77,
+    Axs,
+    Isb,
+}
+
+impl OpeKind {
+    /// Upper case name, as the bundled nestest.log prints it.
+    pub const fn mnemonic(self) -> &'static str {
+        match self {
+            OpeKind::Adc => "ADC",
+            OpeKind::Sbc => "SBC",
+            OpeKind::And => "AND",
+            OpeKind::Ora => "ORA",
+            OpeKind::Eor => "EOR",
+            OpeKind::Asl => "ASL",
+            OpeKind::Lsr => "LSR",
+            OpeKind::Rol => "ROL",
+            OpeKind::Ror => "ROR",
+            OpeKind::Bcc => "BCC",
+            OpeKind::Bcs => "BCS",
+            OpeKind::Beq => "BEQ",
+            OpeKind::Bne => "BNE",
+            OpeKind::Bvc => "BVC",
+            OpeKind::Bvs => "BVS",
+            OpeKind::Bpl => "BPL",
+            OpeKind::Bmi => "BMI",
+            OpeKind::Bit => "BIT",
+            OpeKind::Jmp => "JMP",
+            OpeKind::Jsr => "JSR",
+            OpeKind::Rts => "RTS",
+            OpeKind::Brk => "BRK",
+            OpeKind::Rti => "RTI",
+            OpeKind::Cmp => "CMP",
+            OpeKind::Cpx => "CPX",
+            OpeKind::Cpy => "CPY",
+            OpeKind::Inc => "INC",
+            OpeKind::Dec => "DEC",
+            OpeKind::Inx => "INX",
+            OpeKind::Dex => "DEX",
+            OpeKind::Iny => "INY",
+            OpeKind::Dey => "DEY",
+            OpeKind::Clc => "CLC",
+            OpeKind::Sec => "SEC",
+            OpeKind::Cli => "CLI",
+            OpeKind::Sei => "SEI",
+            OpeKind::Cld => "CLD",
+            OpeKind::Sed => "SED",
+            OpeKind::Clv => "CLV",
+            OpeKind::Lda => "LDA",
+            OpeKind::Ldx => "LDX",
+            OpeKind::Ldy => "LDY",
+            OpeKind::Sta => "STA",
+            OpeKind::Stx => "STX",
+            OpeKind::Sty => "STY",
+            OpeKind::Tax => "TAX",
+            OpeKind::Txa => "TXA",
+            OpeKind::Tay => "TAY",
+            OpeKind::Tya => "TYA",
+            OpeKind::Tsx => "TSX",
+            OpeKind::Txs => "TXS",
+            OpeKind::Pha => "PHA",
+            OpeKind::Pla => "PLA",
+            OpeKind::Php => "PHP",
+            OpeKind::Plp => "PLP",
+            OpeKind::Nop => "NOP",
+            OpeKind::Dop => "DOP",
+            OpeKind::Kil => "KIL",
+            OpeKind::Slo => "SLO",
+            OpeKind::Aac => "AAC",
+            OpeKind::Atx => "ATX",
+            OpeKind::Top => "TOP",
+            OpeKind::Rla => "RLA",
+            OpeKind::Sre => "SRE",
+            OpeKind::Dcp => "DCP",
+            OpeKind::Asr => "ASR",
+            OpeKind::Rra => "RRA",
+            OpeKind::Arr => "ARR",
+            OpeKind::Sax => "SAX",
+            OpeKind::Xaa => "XAA",
+            OpeKind::Axa => "AXA",
+            OpeKind::Xas => "XAS",
+            OpeKind::Sxa => "SXA",
+            OpeKind::Sya => "SYA",
+            OpeKind::Lax => "LAX",
+            OpeKind::Lxa => "LXA",
+            OpeKind::Las => "LAS",
+            OpeKind::Axs => "AXS",
+            OpeKind::Isb => "ISB",
+        }
+    }
+
+    /// Kinds from `Dop` on are the undocumented ones.
+    pub const fn is_illegal(self) -> bool {
+        self as u8 >= OpeKind::Dop as u8
+    }
+}
+
+/// One entry of the `operators` table in `debug.veryl`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Operator {
+    pub ope_kind: OpeKind,
+    pub addr_mode: AddrMode,
+    pub cycles: u8,
+}
+
+impl Operator {
+    const fn new(ope_kind: OpeKind, addr_mode: AddrMode, cycles: u8) -> Self {
+        Self {
+            ope_kind,
+            addr_mode,
+            cycles,
+        }
+    }
+
+    pub fn decode(opcode: u8) -> Self {
+        OPERATORS[opcode as usize]
+    }
+
+    /// Operand bytes following the opcode.
+    pub const fn operand_len(&self) -> usize {
+        self.addr_mode.operand_len()
+    }
+
+    /// Bytes of the whole instruction, opcode included.
+    pub const fn inst_len(&self) -> usize {
+        1 + self.operand_len()
+    }
+
+    /// Cycles added to [`Operator::cycles`] when indexing crosses a page. A branch takes one
+    /// more on top of this whenever it is taken.
+    pub const fn page_cross_cycles(&self) -> u8 {
+        match (self.ope_kind, self.addr_mode) {
+            (_, AddrMode::Rel) => 1,
+            (
+                OpeKind::Adc
+                | OpeKind::Sbc
+                | OpeKind::And
+                | OpeKind::Ora
+                | OpeKind::Eor
+                | OpeKind::Cmp
+                | OpeKind::Lda
+                | OpeKind::Ldx
+                | OpeKind::Ldy
+                | OpeKind::Lax
+                | OpeKind::Las
+                | OpeKind::Top,
+                AddrMode::AbsX | AddrMode::AbsY | AddrMode::IndY,
+            ) => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Whether `opcode` is outside the documented instruction set. Besides the illegal kinds,
+/// that covers the `Nop` addressing mode and 0xeb, a copy of `SBC #imm`.
+pub const fn is_illegal(opcode: u8) -> bool {
+    let operator = OPERATORS[opcode as usize];
+    opcode == 0xeb || operator.ope_kind.is_illegal() || matches!(operator.addr_mode, AddrMode::Nop)
+}
+
+/// Decode table indexed by opcode.
+///
+/// `debug.veryl` declares its table with `8'hff` entries, so 0xff is filled in here with
+/// the regular ISB absolute,X encoding.
+pub const OPERATORS: [Operator; 256] = [
+    Operator::new(OpeKind::Brk, AddrMode::Impl, 7), // 0x00
+    Operator::new(OpeKind::Ora, AddrMode::IndX, 6), // 0x01
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x02
+    Operator::new(OpeKind::Slo, AddrMode::IndX, 8), // 0x03
+    Operator::new(OpeKind::Dop, AddrMode::Zp, 3),   // 0x04
+    Operator::new(OpeKind::Ora, AddrMode::Zp, 3),   // 0x05
+    Operator::new(OpeKind::Asl, AddrMode::Zp, 5),   // 0x06
+    Operator::new(OpeKind::Slo, AddrMode::Zp, 5),   // 0x07
+    Operator::new(OpeKind::Php, AddrMode::Impl, 3), // 0x08
+    Operator::new(OpeKind::Ora, AddrMode::Imm, 2),  // 0x09
+    Operator::new(OpeKind::Asl, AddrMode::Acc, 2),  // 0x0a
+    Operator::new(OpeKind::Aac, AddrMode::Imm, 2),  // 0x0b
+    Operator::new(OpeKind::Top, AddrMode::Abs, 4),  // 0x0c
+    Operator::new(OpeKind::Ora, AddrMode::Abs, 4),  // 0x0d
+    Operator::new(OpeKind::Asl, AddrMode::Abs, 6),  // 0x0e
+    Operator::new(OpeKind::Slo, AddrMode::Abs, 6),  // 0x0f
+    Operator::new(OpeKind::Bpl, AddrMode::Rel, 2),  // 0x10
+    Operator::new(OpeKind::Ora, AddrMode::IndY, 5), // 0x11
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x12
+    Operator::new(OpeKind::Slo, AddrMode::IndY, 8), // 0x13
+    Operator::new(OpeKind::Dop, AddrMode::ZpX, 4),  // 0x14
+    Operator::new(OpeKind::Ora, AddrMode::ZpX, 4),  // 0x15
+    Operator::new(OpeKind::Asl, AddrMode::ZpX, 6),  // 0x16
+    Operator::new(OpeKind::Slo, AddrMode::ZpX, 6),  // 0x17
+    Operator::new(OpeKind::Clc, AddrMode::Impl, 2), // 0x18
+    Operator::new(OpeKind::Ora, AddrMode::AbsY, 4), // 0x19
+    Operator::new(OpeKind::Nop, AddrMode::Nop, 2),  // 0x1a
+    Operator::new(OpeKind::Slo, AddrMode::AbsY, 7), // 0x1b
+    Operator::new(OpeKind::Top, AddrMode::AbsX, 4), // 0x1c
+    Operator::new(OpeKind::Ora, AddrMode::AbsX, 4), // 0x1d
+    Operator::new(OpeKind::Asl, AddrMode::AbsX, 6), // 0x1e
+    Operator::new(OpeKind::Slo, AddrMode::AbsX, 7), // 0x1f
+    Operator::new(OpeKind::Jsr, AddrMode::Abs, 6),  // 0x20
+    Operator::new(OpeKind::And, AddrMode::IndX, 6), // 0x21
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x22
+    Operator::new(OpeKind::Rla, AddrMode::IndX, 8), // 0x23
+    Operator::new(OpeKind::Bit, AddrMode::Zp, 3),   // 0x24
+    Operator::new(OpeKind::And, AddrMode::Zp, 3),   // 0x25
+    Operator::new(OpeKind::Rol, AddrMode::Zp, 5),   // 0x26
+    Operator::new(OpeKind::Rla, AddrMode::Zp, 5),   // 0x27
+    Operator::new(OpeKind::Plp, AddrMode::Impl, 4), // 0x28
+    Operator::new(OpeKind::And, AddrMode::Imm, 2),  // 0x29
+    Operator::new(OpeKind::Rol, AddrMode::Acc, 2),  // 0x2a
+    Operator::new(OpeKind::Aac, AddrMode::Imm, 2),  // 0x2b
+    Operator::new(OpeKind::Bit, AddrMode::Abs, 4),  // 0x2c
+    Operator::new(OpeKind::And, AddrMode::Abs, 4),  // 0x2d
+    Operator::new(OpeKind::Rol, AddrMode::Abs, 6),  // 0x2e
+    Operator::new(OpeKind::Rla, AddrMode::Abs, 6),  // 0x2f
+    Operator::new(OpeKind::Bmi, AddrMode::Rel, 2),  // 0x30
+    Operator::new(OpeKind::And, AddrMode::IndY, 5), // 0x31
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x32
+    Operator::new(OpeKind::Rla, AddrMode::IndY, 8), // 0x33
+    Operator::new(OpeKind::Dop, AddrMode::ZpX, 4),  // 0x34
+    Operator::new(OpeKind::And, AddrMode::ZpX, 4),  // 0x35
+    Operator::new(OpeKind::Rol, AddrMode::ZpX, 6),  // 0x36
+    Operator::new(OpeKind::Rla, AddrMode::ZpX, 6),  // 0x37
+    Operator::new(OpeKind::Sec, AddrMode::Impl, 2), // 0x38
+    Operator::new(OpeKind::And, AddrMode::AbsY, 4), // 0x39
+    Operator::new(OpeKind::Nop, AddrMode::Nop, 2),  // 0x3a
+    Operator::new(OpeKind::Rla, AddrMode::AbsY, 7), // 0x3b
+    Operator::new(OpeKind::Top, AddrMode::AbsX, 4), // 0x3c
+    Operator::new(OpeKind::And, AddrMode::AbsX, 4), // 0x3d
+    Operator::new(OpeKind::Rol, AddrMode::AbsX, 6), // 0x3e
+    Operator::new(OpeKind::Rla, AddrMode::AbsX, 7), // 0x3f
+    Operator::new(OpeKind::Rti, AddrMode::Impl, 6), // 0x40
+    Operator::new(OpeKind::Eor, AddrMode::IndX, 6), // 0x41
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x42
+    Operator::new(OpeKind::Sre, AddrMode::IndX, 8), // 0x43
+    Operator::new(OpeKind::Dop, AddrMode::Zp, 3),   // 0x44
+    Operator::new(OpeKind::Eor, AddrMode::Zp, 3),   // 0x45
+    Operator::new(OpeKind::Lsr, AddrMode::Zp, 5),   // 0x46
+    Operator::new(OpeKind::Sre, AddrMode::Zp, 5),   // 0x47
+    Operator::new(OpeKind::Pha, AddrMode::Impl, 3), // 0x48
+    Operator::new(OpeKind::Eor, AddrMode::Imm, 2),  // 0x49
+    Operator::new(OpeKind::Lsr, AddrMode::Acc, 2),  // 0x4a
+    Operator::new(OpeKind::Asr, AddrMode::Imm, 2),  // 0x4b
+    Operator::new(OpeKind::Jmp, AddrMode::Abs, 3),  // 0x4c
+    Operator::new(OpeKind::Eor, AddrMode::Abs, 4),  // 0x4d
+    Operator::new(OpeKind::Lsr, AddrMode::Abs, 6),  // 0x4e
+    Operator::new(OpeKind::Sre, AddrMode::Abs, 6),  // 0x4f
+    Operator::new(OpeKind::Bvc, AddrMode::Rel, 2),  // 0x50
+    Operator::new(OpeKind::Eor, AddrMode::IndY, 5), // 0x51
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x52
+    Operator::new(OpeKind::Sre, AddrMode::IndY, 8), // 0x53
+    Operator::new(OpeKind::Dop, AddrMode::ZpX, 4),  // 0x54
+    Operator::new(OpeKind::Eor, AddrMode::ZpX, 4),  // 0x55
+    Operator::new(OpeKind::Lsr, AddrMode::ZpX, 6),  // 0x56
+    Operator::new(OpeKind::Sre, AddrMode::ZpX, 6),  // 0x57
+    Operator::new(OpeKind::Cli, AddrMode::Impl, 2), // 0x58
+    Operator::new(OpeKind::Eor, AddrMode::AbsY, 4), // 0x59
+    Operator::new(OpeKind::Nop, AddrMode::Nop, 2),  // 0x5a
+    Operator::new(OpeKind::Sre, AddrMode::AbsY, 7), // 0x5b
+    Operator::new(OpeKind::Top, AddrMode::AbsX, 4), // 0x5c
+    Operator::new(OpeKind::Eor, AddrMode::AbsX, 4), // 0x5d
+    Operator::new(OpeKind::Lsr, AddrMode::AbsX, 6), // 0x5e
+    Operator::new(OpeKind::Sre, AddrMode::AbsX, 7), // 0x5f
+    Operator::new(OpeKind::Rts, AddrMode::Impl, 6), // 0x60
+    Operator::new(OpeKind::Adc, AddrMode::IndX, 6), // 0x61
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x62
+    Operator::new(OpeKind::Rra, AddrMode::IndX, 8), // 0x63
+    Operator::new(OpeKind::Dop, AddrMode::Zp, 3),   // 0x64
+    Operator::new(OpeKind::Adc, AddrMode::Zp, 3),   // 0x65
+    Operator::new(OpeKind::Ror, AddrMode::Zp, 5),   // 0x66
+    Operator::new(OpeKind::Rra, AddrMode::Zp, 5),   // 0x67
+    Operator::new(OpeKind::Pla, AddrMode::Impl, 4), // 0x68
+    Operator::new(OpeKind::Adc, AddrMode::Imm, 2),  // 0x69
+    Operator::new(OpeKind::Ror, AddrMode::Acc, 2),  // 0x6a
+    Operator::new(OpeKind::Arr, AddrMode::Imm, 2),  // 0x6b
+    Operator::new(OpeKind::Jmp, AddrMode::Ind, 5),  // 0x6c
+    Operator::new(OpeKind::Adc, AddrMode::Abs, 4),  // 0x6d
+    Operator::new(OpeKind::Ror, AddrMode::Abs, 6),  // 0x6e
+    Operator::new(OpeKind::Rra, AddrMode::Abs, 6),  // 0x6f
+    Operator::new(OpeKind::Bvs, AddrMode::Rel, 2),  // 0x70
+    Operator::new(OpeKind::Adc, AddrMode::IndY, 5), // 0x71
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x72
+    Operator::new(OpeKind::Rra, AddrMode::IndY, 8), // 0x73
+    Operator::new(OpeKind::Dop, AddrMode::ZpX, 4),  // 0x74
+    Operator::new(OpeKind::Adc, AddrMode::ZpX, 4),  // 0x75
+    Operator::new(OpeKind::Ror, AddrMode::ZpX, 6),  // 0x76
+    Operator::new(OpeKind::Rra, AddrMode::ZpX, 6),  // 0x77
+    Operator::new(OpeKind::Sei, AddrMode::Impl, 2), // 0x78
+    Operator::new(OpeKind::Adc, AddrMode::AbsY, 4), // 0x79
+    Operator::new(OpeKind::Nop, AddrMode::Nop, 2),  // 0x7a
+    Operator::new(OpeKind::Rra, AddrMode::AbsY, 7), // 0x7b
+    Operator::new(OpeKind::Top, AddrMode::AbsX, 4), // 0x7c
+    Operator::new(OpeKind::Adc, AddrMode::AbsX, 4), // 0x7d
+    Operator::new(OpeKind::Ror, AddrMode::AbsX, 6), // 0x7e
+    Operator::new(OpeKind::Rra, AddrMode::AbsX, 7), // 0x7f
+    Operator::new(OpeKind::Dop, AddrMode::Imm, 2),  // 0x80
+    Operator::new(OpeKind::Sta, AddrMode::IndX, 6), // 0x81
+    Operator::new(OpeKind::Dop, AddrMode::Imm, 6),  // 0x82
+    Operator::new(OpeKind::Sax, AddrMode::IndX, 6), // 0x83
+    Operator::new(OpeKind::Sty, AddrMode::Zp, 3),   // 0x84
+    Operator::new(OpeKind::Sta, AddrMode::Zp, 3),   // 0x85
+    Operator::new(OpeKind::Stx, AddrMode::Zp, 3),   // 0x86
+    Operator::new(OpeKind::Sax, AddrMode::Zp, 3),   // 0x87
+    Operator::new(OpeKind::Dey, AddrMode::Impl, 2), // 0x88
+    Operator::new(OpeKind::Dop, AddrMode::Imm, 2),  // 0x89
+    Operator::new(OpeKind::Txa, AddrMode::Impl, 2), // 0x8a
+    Operator::new(OpeKind::Xaa, AddrMode::Imm, 2),  // 0x8b
+    Operator::new(OpeKind::Sty, AddrMode::Abs, 4),  // 0x8c
+    Operator::new(OpeKind::Sta, AddrMode::Abs, 4),  // 0x8d
+    Operator::new(OpeKind::Stx, AddrMode::Abs, 4),  // 0x8e
+    Operator::new(OpeKind::Sax, AddrMode::Abs, 4),  // 0x8f
+    Operator::new(OpeKind::Bcc, AddrMode::Rel, 2),  // 0x90
+    Operator::new(OpeKind::Sta, AddrMode::IndY, 5), // 0x91
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0x92
+    Operator::new(OpeKind::Axa, AddrMode::ZpY, 6),  // 0x93
+    Operator::new(OpeKind::Sty, AddrMode::ZpX, 4),  // 0x94
+    Operator::new(OpeKind::Sta, AddrMode::ZpX, 4),  // 0x95
+    Operator::new(OpeKind::Stx, AddrMode::ZpY, 4),  // 0x96
+    Operator::new(OpeKind::Sax, AddrMode::ZpY, 4),  // 0x97
+    Operator::new(OpeKind::Tya, AddrMode::Impl, 2), // 0x98
+    Operator::new(OpeKind::Sta, AddrMode::AbsY, 5), // 0x99
+    Operator::new(OpeKind::Txs, AddrMode::Impl, 2), // 0x9a
+    Operator::new(OpeKind::Xas, AddrMode::AbsY, 5), // 0x9b
+    Operator::new(OpeKind::Sya, AddrMode::AbsX, 5), // 0x9c
+    Operator::new(OpeKind::Sta, AddrMode::AbsX, 4), // 0x9d
+    Operator::new(OpeKind::Sxa, AddrMode::AbsX, 5), // 0x9e
+    Operator::new(OpeKind::Axa, AddrMode::AbsY, 5), // 0x9f
+    Operator::new(OpeKind::Ldy, AddrMode::Imm, 2),  // 0xa0
+    Operator::new(OpeKind::Lda, AddrMode::IndX, 6), // 0xa1
+    Operator::new(OpeKind::Ldx, AddrMode::Imm, 2),  // 0xa2
+    Operator::new(OpeKind::Lax, AddrMode::IndX, 6), // 0xa3
+    Operator::new(OpeKind::Ldy, AddrMode::Zp, 3),   // 0xa4
+    Operator::new(OpeKind::Lda, AddrMode::Zp, 3),   // 0xa5
+    Operator::new(OpeKind::Ldx, AddrMode::Zp, 3),   // 0xa6
+    Operator::new(OpeKind::Lax, AddrMode::Zp, 3),   // 0xa7
+    Operator::new(OpeKind::Tay, AddrMode::Impl, 2), // 0xa8
+    Operator::new(OpeKind::Lda, AddrMode::Imm, 2),  // 0xa9
+    Operator::new(OpeKind::Tax, AddrMode::Impl, 2), // 0xaa
+    Operator::new(OpeKind::Lxa, AddrMode::Imm, 2),  // 0xab
+    Operator::new(OpeKind::Ldy, AddrMode::Abs, 4),  // 0xac
+    Operator::new(OpeKind::Lda, AddrMode::Abs, 4),  // 0xad
+    Operator::new(OpeKind::Ldx, AddrMode::Abs, 4),  // 0xae
+    Operator::new(OpeKind::Lax, AddrMode::Abs, 4),  // 0xaf
+    Operator::new(OpeKind::Bcs, AddrMode::Rel, 2),  // 0xb0
+    Operator::new(OpeKind::Lda, AddrMode::IndY, 5), // 0xb1
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0xb2
+    Operator::new(OpeKind::Lax, AddrMode::IndY, 5), // 0xb3
+    Operator::new(OpeKind::Ldy, AddrMode::ZpX, 4),  // 0xb4
+    Operator::new(OpeKind::Lda, AddrMode::ZpX, 4),  // 0xb5
+    Operator::new(OpeKind::Ldx, AddrMode::ZpY, 4),  // 0xb6
+    Operator::new(OpeKind::Lax, AddrMode::ZpY, 4),  // 0xb7
+    Operator::new(OpeKind::Clv, AddrMode::Impl, 2), // 0xb8
+    Operator::new(OpeKind::Lda, AddrMode::AbsY, 4), // 0xb9
+    Operator::new(OpeKind::Tsx, AddrMode::Impl, 2), // 0xba
+    Operator::new(OpeKind::Las, AddrMode::AbsY, 4), // 0xbb
+    Operator::new(OpeKind::Ldy, AddrMode::AbsX, 4), // 0xbc
+    Operator::new(OpeKind::Lda, AddrMode::AbsX, 4), // 0xbd
+    Operator::new(OpeKind::Ldx, AddrMode::AbsY, 4), // 0xbe
+    Operator::new(OpeKind::Lax, AddrMode::AbsY, 4), // 0xbf
+    Operator::new(OpeKind::Cpy, AddrMode::Imm, 2),  // 0xc0
+    Operator::new(OpeKind::Cmp, AddrMode::IndX, 6), // 0xc1
+    Operator::new(OpeKind::Dop, AddrMode::Imm, 2),  // 0xc2
+    Operator::new(OpeKind::Dcp, AddrMode::IndX, 8), // 0xc3
+    Operator::new(OpeKind::Cpy, AddrMode::Zp, 3),   // 0xc4
+    Operator::new(OpeKind::Cmp, AddrMode::Zp, 3),   // 0xc5
+    Operator::new(OpeKind::Dec, AddrMode::Zp, 5),   // 0xc6
+    Operator::new(OpeKind::Dcp, AddrMode::Zp, 5),   // 0xc7
+    Operator::new(OpeKind::Iny, AddrMode::Impl, 2), // 0xc8
+    Operator::new(OpeKind::Cmp, AddrMode::Imm, 2),  // 0xc9
+    Operator::new(OpeKind::Dex, AddrMode::Impl, 2), // 0xca
+    Operator::new(OpeKind::Axs, AddrMode::Imm, 2),  // 0xcb
+    Operator::new(OpeKind::Cpy, AddrMode::Abs, 4),  // 0xcc
+    Operator::new(OpeKind::Cmp, AddrMode::Abs, 4),  // 0xcd
+    Operator::new(OpeKind::Dec, AddrMode::Abs, 6),  // 0xce
+    Operator::new(OpeKind::Dcp, AddrMode::Abs, 6),  // 0xcf
+    Operator::new(OpeKind::Bne, AddrMode::Rel, 2),  // 0xd0
+    Operator::new(OpeKind::Cmp, AddrMode::IndY, 5), // 0xd1
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0xd2
+    Operator::new(OpeKind::Dcp, AddrMode::IndY, 8), // 0xd3
+    Operator::new(OpeKind::Dop, AddrMode::ZpX, 4),  // 0xd4
+    Operator::new(OpeKind::Cmp, AddrMode::ZpX, 4),  // 0xd5
+    Operator::new(OpeKind::Dec, AddrMode::ZpX, 6),  // 0xd6
+    Operator::new(OpeKind::Dcp, AddrMode::ZpX, 6),  // 0xd7
+    Operator::new(OpeKind::Cld, AddrMode::Impl, 2), // 0xd8
+    Operator::new(OpeKind::Cmp, AddrMode::AbsY, 4), // 0xd9
+    Operator::new(OpeKind::Nop, AddrMode::Nop, 2),  // 0xda
+    Operator::new(OpeKind::Dcp, AddrMode::AbsY, 7), // 0xdb
+    Operator::new(OpeKind::Top, AddrMode::AbsX, 4), // 0xdc
+    Operator::new(OpeKind::Cmp, AddrMode::AbsX, 4), // 0xdd
+    Operator::new(OpeKind::Dec, AddrMode::AbsX, 7), // 0xde
+    Operator::new(OpeKind::Dcp, AddrMode::AbsX, 7), // 0xdf
+    Operator::new(OpeKind::Cpx, AddrMode::Imm, 2),  // 0xe0
+    Operator::new(OpeKind::Sbc, AddrMode::IndX, 6), // 0xe1
+    Operator::new(OpeKind::Dop, AddrMode::Imm, 3),  // 0xe2
+    Operator::new(OpeKind::Isb, AddrMode::IndX, 8), // 0xe3
+    Operator::new(OpeKind::Cpx, AddrMode::Zp, 3),   // 0xe4
+    Operator::new(OpeKind::Sbc, AddrMode::Zp, 3),   // 0xe5
+    Operator::new(OpeKind::Inc, AddrMode::Zp, 5),   // 0xe6
+    Operator::new(OpeKind::Isb, AddrMode::Zp, 5),   // 0xe7
+    Operator::new(OpeKind::Inx, AddrMode::Impl, 2), // 0xe8
+    Operator::new(OpeKind::Sbc, AddrMode::Imm, 2),  // 0xe9
+    Operator::new(OpeKind::Nop, AddrMode::Impl, 2), // 0xea
+    Operator::new(OpeKind::Sbc, AddrMode::Imm, 2),  // 0xeb
+    Operator::new(OpeKind::Cpx, AddrMode::Abs, 4),  // 0xec
+    Operator::new(OpeKind::Sbc, AddrMode::Abs, 4),  // 0xed
+    Operator::new(OpeKind::Inc, AddrMode::Abs, 6),  // 0xee
+    Operator::new(OpeKind::Isb, AddrMode::Abs, 6),  // 0xef
+    Operator::new(OpeKind::Beq, AddrMode::Rel, 2),  // 0xf0
+    Operator::new(OpeKind::Sbc, AddrMode::IndY, 5), // 0xf1
+    Operator::new(OpeKind::Kil, AddrMode::Impl, 2), // 0xf2
+    Operator::new(OpeKind::Isb, AddrMode::IndY, 8), // 0xf3
+    Operator::new(OpeKind::Dop, AddrMode::ZpX, 4),  // 0xf4
+    Operator::new(OpeKind::Sbc, AddrMode::ZpX, 4),  // 0xf5
+    Operator::new(OpeKind::Inc, AddrMode::ZpX, 6),  // 0xf6
+    Operator::new(OpeKind::Isb, AddrMode::ZpX, 6),  // 0xf7
+    Operator::new(OpeKind::Sed, AddrMode::Impl, 2), // 0xf8
+    Operator::new(OpeKind::Sbc, AddrMode::AbsY, 4), // 0xf9
+    Operator::new(OpeKind::Nop, AddrMode::Nop, 2),  // 0xfa
+    Operator::new(OpeKind::Isb, AddrMode::AbsY, 7), // 0xfb
+    Operator::new(OpeKind::Top, AddrMode::AbsX, 4), // 0xfc
+    Operator::new(OpeKind::Sbc, AddrMode::AbsX, 4), // 0xfd
+    Operator::new(OpeKind::Inc, AddrMode::AbsX, 7), // 0xfe
+    Operator::new(OpeKind::Isb, AddrMode::AbsX, 7), // 0xff
+];
@@ -0,0 +1,108 @@
+use crate::memory::Memory;
+use embassy_fc2_app::cpu::bus::Bus;
+use embassy_fc2_app::middleware::mode::CassetteMode;
+use std::collections::VecDeque;
+
+/// Bus used for one attempt to execute an instruction.
+///
+/// Nothing is committed here. If the host has not sent enough bytes yet the attempt is
+/// marked as starved and replayed once more bytes arrive.
+pub struct SimBus<'a> {
+    memory: &'a Memory,
+    cassette_mode: CassetteMode,
+    mock_memory: bool,
+    fetch_from_rx: bool,
+    rx: &'a VecDeque<u8>,
+    /// Last byte read from memory, which is what an address without storage reads.
+    pub data_bus: u8,
+    pub consumed: usize,
+    pub tx: Vec<u8>,
+    pub writes: Vec<(u16, u8)>,
+    pub opcode: Option<u8>,
+    pub starved: bool,
+}
+
+impl<'a> SimBus<'a> {
+    pub fn new(
+        memory: &'a Memory,
+        cassette_mode: CassetteMode,
+        data_bus: u8,
+        mock_memory: bool,
+        fetch_from_rx: bool,
+        rx: &'a VecDeque<u8>,
+    ) -> Self {
+        Self {
+            memory,
+            cassette_mode,
+            mock_memory,
+            fetch_from_rx,
+            rx,
+            data_bus,
+            consumed: 0,
+            tx: Vec::new(),
+            writes: Vec::new(),
+            opcode: None,
+            starved: false,
+        }
+    }
+
+    fn receive(&mut self) -> u8 {
+        if self.starved {
+            return 0;
+        }
+        match self.rx.get(self.consumed) {
+            Some(data) => {
+                self.consumed += 1;
+                *data
+            }
+            None => {
+                self.starved = true;
+                0
+            }
+        }
+    }
+
+    fn transmit(&mut self, data: &[u8]) {
+        if !self.starved {
+            self.tx.extend_from_slice(data);
+        }
+    }
+}
+
+impl Bus for SimBus<'_> {
+    fn read(&mut self, addr: u16) -> u8 {
+        if self.mock_memory {
+            self.transmit(&addr.to_le_bytes());
+            return self.receive();
+        }
+        let data = match self.writes.iter().rev().find(|(a, _)| *a == addr) {
+            Some((_, data)) => Some(*data),
+            None => self.memory.read(addr, self.cassette_mode),
+        };
+        if let Some(data) = data {
+            self.data_bus = data;
+        }
+        self.data_bus
+    }
+
+    fn write(&mut self, addr: u16, data: u8) {
+        if self.mock_memory {
+            let [low, high] = addr.to_le_bytes();
+            self.transmit(&[low, high, data]);
+        } else if !self.starved {
+            self.writes.push((addr, data));
+        }
+    }
+
+    fn fetch(&mut self, addr: u16) -> u8 {
+        let data = if self.fetch_from_rx {
+            self.receive()
+        } else {
+            self.read(addr)
+        };
+        if self.opcode.is_none() && !self.starved {
+            self.opcode = Some(data);
+        }
+        data
+    }
+}
@@ -0,0 +1,444 @@
+//! Host side stand-in for the FPGA `Cpu6502` and `Debug` modules.
+//!
+//! Bytes written by the host are interpreted the same way the FPGA does over usart, and
+//! instructions run on the reference cpu of `embassy_fc2_app`.
+
+mod bus;
+pub mod memory;
+
+use bus::SimBus;
+use embassy_fc2_app::cpu::{Cpu, Registers};
+use embassy_fc2_app::middleware::mode::{CassetteMode, CpuMode, OpeMode, TxReg};
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::operators::{OpeKind, Operator};
+use memory::Memory;
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Status {
+    ReadCpuMode,
+    ReadCassetteMode,
+    Ready,
+    InstRead,
+    SequenceCountRead,
+    TxRegisterDetect,
+    // cpu mode is nop, so every byte is ignored until reset.
+    Halt,
+}
+
+pub struct DebugSimulator {
+    status: Status,
+    cpu_mode: CpuMode,
+    cassette_mode: CassetteMode,
+    cpu: Cpu,
+    memory: Memory,
+    // last byte `Ram` put out, which an address without storage reads back.
+    data_bus: u8,
+    sequence_count: u8,
+    rw: bool,
+    rx: VecDeque<u8>,
+    tx: VecDeque<u8>,
+    // tx bytes already sent for the instruction waiting on rx.
+    emitted: usize,
+}
+
+impl DebugSimulator {
+    pub fn new() -> Self {
+        Self {
+            status: Status::ReadCpuMode,
+            cpu_mode: CpuMode::Nop,
+            cassette_mode: CassetteMode::Nop,
+            cpu: Cpu::new(),
+            memory: Memory::new(),
+            data_bus: 0,
+            sequence_count: 0,
+            rw: true,
+            rx: VecDeque::new(),
+            tx: VecDeque::new(),
+            emitted: 0,
+        }
+    }
+
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    pub fn memory_mut(&mut self) -> &mut Memory {
+        &mut self.memory
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.cpu.registers
+    }
+
+    /// Level of the RW pin. High means read.
+    pub fn rw(&self) -> bool {
+        match self.status {
+            Status::ReadCpuMode | Status::ReadCassetteMode | Status::Halt => true,
+            _ => self.rw,
+        }
+    }
+
+    /// Level of the Nop pin. High while cpu mode is nop.
+    pub fn nop(&self) -> bool {
+        matches!(self.cpu_mode, CpuMode::Nop)
+    }
+
+    /// Same as raising RESB. Registers and modes are reset, memory is kept.
+    pub fn reset(&mut self) {
+        self.status = Status::ReadCpuMode;
+        self.cpu_mode = CpuMode::Nop;
+        self.cassette_mode = CassetteMode::Nop;
+        self.cpu.reset();
+        self.data_bus = 0;
+        self.sequence_count = 0;
+        self.rw = true;
+        self.rx.clear();
+        self.tx.clear();
+        self.emitted = 0;
+    }
+
+    /// Receives bytes sent by the host and runs as far as they allow.
+    pub fn write(&mut self, data: &[u8]) {
+        self.rx.extend(data);
+        self.process();
+    }
+
+    /// Sends pending bytes to the host and returns how many were copied.
+    pub fn read(&mut self, buf: &mut [u8]) -> usize {
+        let len = buf.len().min(self.tx.len());
+        for (dst, src) in buf.iter_mut().zip(self.tx.drain(..len)) {
+            *dst = src;
+        }
+        len
+    }
+
+    pub fn pending_tx(&self) -> usize {
+        self.tx.len()
+    }
+
+    fn process(&mut self) {
+        loop {
+            match self.status {
+                Status::ReadCpuMode => {
+                    let Some(data) = self.rx.pop_front() else {
+                        return;
+                    };
+                    self.cpu_mode = select_cpu_mode(data);
+                    self.status = Status::ReadCassetteMode;
+                }
+                Status::ReadCassetteMode => {
+                    let Some(data) = self.rx.pop_front() else {
+                        return;
+                    };
+                    self.cassette_mode = select_cassette_mode(data);
+                    self.status = match self.cpu_mode {
+                        CpuMode::DebugWithinMockMemory | CpuMode::DebugWithinInternalMemory => {
+                            self.rw = false;
+                            Status::Ready
+                        }
+                        _ => Status::Halt,
+                    };
+                }
+                Status::Halt => {
+                    self.rx.clear();
+                    return;
+                }
+                Status::Ready => {
+                    if self.sequence_count > 0 {
+                        if !self.execute(false) {
+                            return;
+                        }
+                        self.sequence_count -= 1;
+                        continue;
+                    }
+                    let Some(data) = self.rx.pop_front() else {
+                        return;
+                    };
+                    // unknown ope modes are dropped as the FPGA does.
+                    match OpeMode::try_from(data) {
+                        Ok(OpeMode::Inst) => self.status = Status::InstRead,
+                        Ok(OpeMode::Sequence) => self.status = Status::SequenceCountRead,
+                        Ok(OpeMode::RegisterTransfer) => self.status = Status::TxRegisterDetect,
+                        Err(_) => {}
+                    }
+                }
+                Status::InstRead => {
+                    if !self.execute(true) {
+                        return;
+                    }
+                    self.status = Status::Ready;
+                }
+                Status::SequenceCountRead => {
+                    let Some(data) = self.rx.pop_front() else {
+                        return;
+                    };
+                    self.sequence_count = data;
+                    self.status = Status::Ready;
+                }
+                Status::TxRegisterDetect => {
+                    let Some(data) = self.rx.pop_front() else {
+                        return;
+                    };
+                    self.transfer_register(data);
+                    self.status = Status::Ready;
+                }
+            }
+        }
+    }
+
+    /// Tries to run one instruction and returns false while it still waits on the host.
+    fn execute(&mut self, fetch_from_rx: bool) -> bool {
+        let mut cpu = self.cpu;
+        let mock_memory = matches!(self.cpu_mode, CpuMode::DebugWithinMockMemory);
+        let mut bus = SimBus::new(
+            &self.memory,
+            self.cassette_mode,
+            self.data_bus,
+            mock_memory,
+            fetch_from_rx,
+            &self.rx,
+        );
+        cpu.step(&mut bus);
+        if let Some(opcode) = bus.opcode {
+            self.rw = !is_write(Operator::decode(opcode).ope_kind);
+        }
+        self.tx.extend(&bus.tx[self.emitted..]);
+        if bus.starved {
+            self.emitted = bus.tx.len();
+            return false;
+        }
+        let consumed = bus.consumed;
+        let writes = bus.writes;
+        self.data_bus = bus.data_bus;
+        self.emitted = 0;
+        self.rx.drain(..consumed);
+        for (addr, data) in writes {
+            self.memory.write(addr, data, self.cassette_mode);
+        }
+        self.cpu = cpu;
+        true
+    }
+
+    fn transfer_register(&mut self, data: u8) {
+        let r = self.cpu.registers;
+        match TxReg::try_from(data) {
+            Ok(TxReg::A) => self.tx.push_back(r.a),
+            Ok(TxReg::X) => self.tx.push_back(r.x),
+            Ok(TxReg::Y) => self.tx.push_back(r.y),
+            Ok(TxReg::S) => self.tx.push_back(r.s),
+            Ok(TxReg::P) => self.tx.push_back(r.p),
+            Ok(TxReg::PC) => self.tx.extend(r.pc.to_le_bytes()),
+            Err(_) => self.tx.push_back(0),
+        }
+    }
+}
+
+/// Failure of the simulator as a [`Transport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The FPGA has not sent enough bytes. A real usart would block forever here.
+    NotEnoughData { requested: usize, available: usize },
+}
+
+impl Transport for DebugSimulator {
+    type Error = Error;
+
+    fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        DebugSimulator::write(self, data);
+        Ok(())
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        if self.tx.len() < buf.len() {
+            return Err(Error::NotEnoughData {
+                requested: buf.len(),
+                available: self.tx.len(),
+            });
+        }
+        DebugSimulator::read(self, buf);
+        Ok(())
+    }
+}
+
+impl ControlPins for DebugSimulator {
+    fn is_rw_high(&mut self) -> bool {
+        self.rw()
+    }
+
+    fn is_nop_high(&mut self) -> bool {
+        self.nop()
+    }
+
+    // the FPGA stays in reset while RESB is high.
+    fn set_resb(&mut self, high: bool) {
+        if high {
+            self.reset();
+        }
+    }
+}
+
+impl Default for DebugSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Same selection as `ModeSelector`. Only the debug modes leave nop.
+fn select_cpu_mode(data: u8) -> CpuMode {
+    match CpuMode::try_from(data) {
+        Ok(cpu_mode @ (CpuMode::DebugWithinMockMemory | CpuMode::DebugWithinInternalMemory)) => {
+            cpu_mode
+        }
+        _ => CpuMode::Nop,
+    }
+}
+
+/// `ModeHandler` only looks at the low two bits.
+fn select_cassette_mode(data: u8) -> CassetteMode {
+    CassetteMode::try_from(data & 0b11).unwrap_or(CassetteMode::Nop)
+}
+
+/// Operations for which `RWHandler` drives RW low.
+fn is_write(ope_kind: OpeKind) -> bool {
+    matches!(
+        ope_kind,
+        OpeKind::Dec
+            | OpeKind::Inc
+            | OpeKind::Sta
+            | OpeKind::Stx
+            | OpeKind::Sty
+            | OpeKind::Php
+            | OpeKind::Dcp
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(cpu_mode: CpuMode) -> DebugSimulator {
+        let mut sim = DebugSimulator::new();
+        sim.write(&[cpu_mode as u8, CassetteMode::None as u8]);
+        sim
+    }
+
+    fn register(sim: &mut DebugSimulator, register: TxReg) -> Vec<u8> {
+        sim.write(&[OpeMode::RegisterTransfer as u8, register as u8]);
+        let mut buf = vec![0x0u8; sim.pending_tx()];
+        sim.read(&mut buf);
+        buf
+    }
+
+    #[test]
+    fn replies_registers_after_reset() {
+        let mut sim = start(CpuMode::DebugWithinInternalMemory);
+        assert!(!sim.nop());
+        assert_eq!(register(&mut sim, TxReg::S), [0xFD]);
+        assert_eq!(register(&mut sim, TxReg::P), [0x24]);
+        assert_eq!(register(&mut sim, TxReg::PC), [0x00, 0x00]);
+    }
+
+    #[test]
+    fn inst_waits_for_its_operands() {
+        let mut sim = start(CpuMode::DebugWithinInternalMemory);
+        sim.write(&[OpeMode::Inst as u8, 0xA9]);
+        assert_eq!(sim.registers(), Registers::default());
+        sim.write(&[0x80]);
+        assert_eq!(register(&mut sim, TxReg::A), [0x80]);
+        assert_eq!(register(&mut sim, TxReg::P), [0xA4]);
+        assert_eq!(register(&mut sim, TxReg::PC), [0x02, 0x00]);
+    }
+
+    #[test]
+    fn nop_mode_ignores_everything_until_reset() {
+        let mut sim = start(CpuMode::Nop);
+        assert!(sim.nop());
+        sim.write(&[OpeMode::RegisterTransfer as u8, TxReg::S as u8]);
+        assert_eq!(sim.pending_tx(), 0);
+        sim.reset();
+        sim.write(&[
+            CpuMode::DebugWithinInternalMemory as u8,
+            CassetteMode::None as u8,
+        ]);
+        assert_eq!(register(&mut sim, TxReg::S), [0xFD]);
+    }
+
+    #[test]
+    fn sequence_runs_from_internal_memory() {
+        let mut sim = start(CpuMode::DebugWithinInternalMemory);
+        for (addr, data) in (0x0600..).zip([0xA2, 0x05, 0xE8]) {
+            sim.memory_mut().write(addr, data, CassetteMode::None);
+        }
+        sim.write(&[OpeMode::Inst as u8, 0x4C, 0x00, 0x06]);
+        sim.write(&[OpeMode::Sequence as u8, 2]);
+        assert_eq!(register(&mut sim, TxReg::X), [0x06]);
+        assert_eq!(register(&mut sim, TxReg::PC), [0x03, 0x06]);
+    }
+
+    #[test]
+    fn mock_memory_asks_for_reads_and_sends_writes() {
+        let mut sim = start(CpuMode::DebugWithinMockMemory);
+        sim.write(&[OpeMode::Inst as u8, 0xAD, 0x78, 0x06]);
+        let mut request = [0x0u8; 2];
+        assert_eq!(sim.read(&mut request), 2);
+        assert_eq!(request, [0x78, 0x06]);
+        assert!(sim.rw());
+        sim.write(&[0x55]);
+        assert_eq!(register(&mut sim, TxReg::A), [0x55]);
+
+        sim.write(&[OpeMode::Inst as u8, 0x8D, 0x00, 0x02]);
+        let mut request = [0x0u8; 3];
+        assert_eq!(sim.read(&mut request), 3);
+        assert_eq!(request, [0x00, 0x02, 0x55]);
+        assert!(!sim.rw());
+    }
+
+    #[test]
+    fn unimplemented_addresses_read_the_last_bus_value() {
+        let mut sim = start(CpuMode::DebugWithinInternalMemory);
+        sim.memory_mut().write(0x0200, 0x5A, CassetteMode::None);
+        // lda $0200, then lda $2002 and lda $3808, which have nothing behind them.
+        for operands in [[0x00, 0x02], [0x02, 0x20], [0x08, 0x38]] {
+            sim.write(&[OpeMode::Inst as u8, 0xAD]);
+            sim.write(&operands);
+            assert_eq!(register(&mut sim, TxReg::A), [0x5A]);
+        }
+    }
+
+    #[test]
+    fn prg_roms_repeat_their_lower_8kib() {
+        let mut memory = Memory::new();
+        memory.write(0xA000, 0x11, CassetteMode::None);
+        memory.write(0xE001, 0x22, CassetteMode::None);
+        assert_eq!(memory.read(0x8000, CassetteMode::None), Some(0x11));
+        assert_eq!(memory.read(0xC001, CassetteMode::None), Some(0x22));
+        memory.write(0xA000, 0x33, CassetteMode::NesTest);
+        assert_eq!(memory.read(0x8000, CassetteMode::NesTest), Some(0x00));
+        assert_eq!(memory.read(0xA000, CassetteMode::NesTest), Some(0x33));
+    }
+
+    #[test]
+    fn every_write_lands_in_apu_io() {
+        let mut memory = Memory::new();
+        memory.write(0x0203, 0x44, CassetteMode::None);
+        assert_eq!(memory.read(0x0203, CassetteMode::None), Some(0x44));
+        assert_eq!(memory.read(0x401B, CassetteMode::None), Some(0x44));
+        // dropped everywhere else, but still taken by `ip_func_apu_io`.
+        memory.write(0x4005, 0x55, CassetteMode::None);
+        assert_eq!(memory.read(0x4005, CassetteMode::None), None);
+        assert_eq!(memory.read(0x401D, CassetteMode::None), Some(0x55));
+    }
+
+    #[test]
+    fn transport_fails_instead_of_blocking() {
+        let mut sim = start(CpuMode::DebugWithinInternalMemory);
+        assert_eq!(
+            Transport::read(&mut sim, &mut [0x0u8; 1]),
+            Err(Error::NotEnoughData {
+                requested: 1,
+                available: 0
+            })
+        );
+    }
+}
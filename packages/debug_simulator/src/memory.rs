@@ -0,0 +1,75 @@
+use embassy_fc2_app::memory_map::{self, Divergence, Region};
+use embassy_fc2_app::middleware::mode::CassetteMode;
+
+/// Internal memory of the FPGA `Ram` module.
+///
+/// Addresses are kept where `Ram` keeps them, so the mirrors of wram and the ppu registers
+/// do not alias what they repeat, the upper half of a prg rom does, and writes the FPGA
+/// drops are dropped here as well. See [`memory_map::divergence`].
+pub struct Memory {
+    ram: Box<[u8; 0x10000]>,
+    // `ip_nestest1_rom` and `ip_nestest2_rom`, separate instances of the same image.
+    nestest: [Box<[u8; 0x4000]>; 2],
+}
+
+impl Memory {
+    pub fn new() -> Self {
+        Self {
+            ram: Box::new([0; 0x10000]),
+            nestest: [Box::new([0; 0x4000]), Box::new([0; 0x4000])],
+        }
+    }
+
+    /// Loads the 16KiB image served by `ip_nestest` at both $8000 and $C000.
+    pub fn load_nestest(&mut self, prg: &[u8]) {
+        let len = prg.len().min(0x4000);
+        for rom in &mut self.nestest {
+            rom[..len].copy_from_slice(&prg[..len]);
+        }
+    }
+
+    /// Byte at `addr`, or `None` where `Ram` has nothing behind it and leaves its data
+    /// output as it was.
+    pub fn read(&self, addr: u16, cassette_mode: CassetteMode) -> Option<u8> {
+        let addr = match memory_map::divergence(addr, false, cassette_mode) {
+            Some(Divergence::Unimplemented | Divergence::BeyondDepth) => return None,
+            Some(Divergence::ShortRom { of }) => of,
+            _ => addr,
+        };
+        match self.nestest_offset(addr, cassette_mode) {
+            Some((rom, offset)) => Some(self.nestest[rom][offset]),
+            None => Some(self.ram[addr as usize]),
+        }
+    }
+
+    pub fn write(&mut self, addr: u16, data: u8, cassette_mode: CassetteMode) {
+        // `ip_func_apu_io` takes every write, whatever else happens to it.
+        self.ram[memory_map::apu_io_echo(addr) as usize] = data;
+        let addr = match memory_map::divergence(addr, true, cassette_mode) {
+            Some(
+                Divergence::Unimplemented | Divergence::BeyondDepth | Divergence::WriteDropped,
+            ) => return,
+            Some(Divergence::ShortRom { of }) => of,
+            _ => addr,
+        };
+        match self.nestest_offset(addr, cassette_mode) {
+            Some((rom, offset)) => self.nestest[rom][offset] = data,
+            None => self.ram[addr as usize] = data,
+        }
+    }
+
+    fn nestest_offset(&self, addr: u16, cassette_mode: CassetteMode) -> Option<(usize, usize)> {
+        let location = memory_map::decode(addr);
+        match (cassette_mode, location.region) {
+            (CassetteMode::NesTest, Region::PrgRom1) => Some((0, location.offset as usize)),
+            (CassetteMode::NesTest, Region::PrgRom2) => Some((1, location.offset as usize)),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
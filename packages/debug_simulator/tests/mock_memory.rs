@@ -0,0 +1,60 @@
+use debug_simulator::DebugSimulator;
+use embassy_fc2_app::middleware::check::CheckKind;
+use embassy_fc2_app::middleware::mock::MockMemory;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::{CassetteMode, CpuMode};
+use embassy_fc2_app::middleware::state::{check_state, read_state};
+use embassy_fc2_app::middleware::util::send_reset_signal_if_not_nop;
+use embassy_fc2_app::{inst, program};
+
+fn start(mock: &mut MockMemory, fc2: &mut DebugSimulator) {
+    send_reset_signal_if_not_nop(fc2).unwrap();
+    mock.send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))
+        .unwrap();
+    mock.send_command(fc2, Command::CassetteMode(CassetteMode::None))
+        .unwrap();
+}
+
+#[test]
+fn serves_a_program_and_records_its_writes() {
+    let mut memory = Box::new([0x0u8; 0x10000]);
+    let mut mock = MockMemory::new(&mut memory);
+    let mut fc2 = DebugSimulator::new();
+    start(&mut mock, &mut fc2);
+    mock.load(0x0600, &program!("lda #$41; sta $0400; inc $0400"));
+    mock.send_command(&mut fc2, inst!("jmp $0600")).unwrap();
+    mock.run(&mut fc2, 3).unwrap();
+
+    check_state(&mut fc2, &mock.state()).unwrap();
+    assert_eq!(read_state(&mut fc2).unwrap().pc, 0x0608);
+    assert_eq!(mock.writes(), [(0x0400, 0x41), (0x0400, 0x42)]);
+    assert_eq!(mock.write_count(), 2);
+    assert_eq!(fc2.pending_tx(), 0);
+
+    let mut expected = Box::new(*mock.memory());
+    expected[0x0400] = 0x42;
+    mock.check_memory::<()>(&expected).unwrap();
+    expected[0x0400] = 0x43;
+    expected[0x0500] = 0x01;
+    let error = mock.check_memory::<()>(&expected).unwrap_err();
+    assert_eq!(error.kind, CheckKind::Memory(0x0400));
+    assert_eq!(error.expected.as_slice(), [0x43]);
+    assert_eq!(error.actual.as_slice(), [0x42]);
+}
+
+#[test]
+fn runs_longer_than_one_sequence_frame() {
+    let mut memory = Box::new([0x0u8; 0x10000]);
+    let mut mock = MockMemory::new(&mut memory);
+    let mut fc2 = DebugSimulator::new();
+    start(&mut mock, &mut fc2);
+    // inx; bne *-1 counts X up to 0 again, 256 times round the loop.
+    mock.load(0x0600, &program!("inx; bne *-1; iny"));
+    mock.send_command(&mut fc2, inst!("jmp $0600")).unwrap();
+    mock.run(&mut fc2, 2 * 256 + 1).unwrap();
+
+    let state = read_state(&mut fc2).unwrap();
+    assert_eq!(state, mock.state());
+    assert_eq!((state.x, state.y, state.pc), (0x00, 0x01, 0x0604));
+    assert!(mock.writes().is_empty());
+}
@@ -0,0 +1,90 @@
+use debug_simulator::DebugSimulator;
+use embassy_fc2_app::middleware::check::{CheckError, CheckKind, CheckResult};
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::{CassetteMode, CpuMode, TxReg};
+use embassy_fc2_app::middleware::runner::{Suite, TestRunner};
+use embassy_fc2_app::middleware::util::{
+    check_valid_register_status, send_command, send_reset_signal_if_not_nop,
+};
+use embassy_fc2_app::{inst, run_test};
+
+fn start(fc2: &mut DebugSimulator) -> CheckResult<DebugSimulator> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))
+}
+
+fn test_ldx(fc2: &mut DebugSimulator) -> CheckResult<DebugSimulator> {
+    start(fc2)?;
+    send_command(fc2, inst!("ldx #$39"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x39])
+}
+
+fn test_ldx_wrong(fc2: &mut DebugSimulator) -> CheckResult<DebugSimulator> {
+    start(fc2)?;
+    send_command(fc2, inst!("ldx #$39"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x40])
+}
+
+/// Passes only on a FPGA that was reset and not set up again.
+fn test_was_reset(fc2: &mut DebugSimulator) -> CheckResult<DebugSimulator> {
+    match fc2.nop() {
+        true => Ok(()),
+        false => Err(CheckError::new(CheckKind::Reset, &[1], &[0])),
+    }
+}
+
+fn passing(runner: &mut TestRunner, fc2: &mut DebugSimulator) {
+    run_test!(runner, fc2, test_ldx);
+}
+
+fn failing(runner: &mut TestRunner, fc2: &mut DebugSimulator) {
+    assert!(!run_test!(runner, fc2, test_ldx_wrong));
+    // the runner resets the FPGA after a failure and keeps going.
+    assert!(run_test!(runner, fc2, test_was_reset));
+    assert!(run_test!(runner, fc2, test_ldx));
+}
+
+fn suites() -> [Suite<DebugSimulator>; 2] {
+    [
+        Suite::new("passing", passing),
+        Suite::new("failing", failing),
+    ]
+}
+
+fn run(filter: Option<&str>) -> TestRunner {
+    let mut runner = TestRunner::new();
+    runner.run_suites(&mut DebugSimulator::new(), &suites(), filter);
+    runner
+}
+
+#[test]
+fn runs_every_suite_without_a_filter() {
+    let runner = run(None);
+    assert_eq!((runner.passed(), runner.failed()), (3, 1));
+}
+
+#[test]
+fn runs_only_the_named_suites() {
+    let runner = run(Some(" passing ,,"));
+    assert_eq!((runner.passed(), runner.failed()), (1, 0));
+    let runner = run(Some("passing,passing"));
+    assert_eq!((runner.passed(), runner.failed()), (2, 0));
+}
+
+#[test]
+fn unknown_suite_counts_as_a_failure() {
+    let runner = run(Some("passing,missing"));
+    assert_eq!((runner.passed(), runner.failed()), (1, 1));
+}
+
+#[test]
+fn finish_returns_when_everything_passed() {
+    run(Some("passing")).finish();
+}
+
+#[test]
+#[should_panic(expected = "1 tests failed")]
+fn finish_panics_after_a_failure() {
+    run(Some("failing")).finish();
+}
@@ -0,0 +1,80 @@
+use debug_simulator::DebugSimulator;
+use embassy_fc2_app::cpu::Registers;
+use embassy_fc2_app::inst;
+use embassy_fc2_app::middleware::check::CheckKind;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::{CassetteMode, CpuMode, TxReg};
+use embassy_fc2_app::middleware::state::{check_state, read_state, StateDiff};
+use embassy_fc2_app::middleware::util::{send_command, send_reset_signal_if_not_nop};
+
+/// Simulator that ran `LDX #$39` from internal memory.
+fn ldx() -> DebugSimulator {
+    let mut fc2 = DebugSimulator::new();
+    send_reset_signal_if_not_nop(&mut fc2).unwrap();
+    send_command(
+        &mut fc2,
+        Command::CpuMode(CpuMode::DebugWithinInternalMemory),
+    )
+    .unwrap();
+    send_command(&mut fc2, Command::CassetteMode(CassetteMode::None)).unwrap();
+    send_command(&mut fc2, inst!("ldx #$39")).unwrap();
+    fc2
+}
+
+#[test]
+fn reads_every_register() {
+    let mut fc2 = ldx();
+    let state = read_state(&mut fc2).unwrap();
+    assert_eq!(state, fc2.registers());
+    assert_eq!((state.a, state.x, state.y), (0x00, 0x39, 0x00));
+    assert_eq!((state.p, state.s), (0x24, 0xFD));
+    assert_eq!(fc2.pending_tx(), 0);
+}
+
+#[test]
+fn matching_state_has_an_empty_diff() {
+    let mut fc2 = ldx();
+    let expected = fc2.registers();
+    check_state(&mut fc2, &expected).unwrap();
+    let diff = StateDiff::new(expected, read_state(&mut fc2).unwrap());
+    assert!(diff.is_empty());
+    assert_eq!(diff.registers().count(), 0);
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn one_differing_register_is_named() {
+    let mut fc2 = ldx();
+    let actual = fc2.registers();
+    let expected = Registers { a: 0x4C, ..actual };
+    let error = check_state(&mut fc2, &expected).unwrap_err();
+    let CheckKind::State(diff) = error.kind else {
+        panic!("not a state mismatch: {:?}", error);
+    };
+    assert_eq!(diff, StateDiff::new(expected, actual));
+    assert_eq!(diff.registers().collect::<Vec<_>>(), [TxReg::A]);
+    assert_eq!(diff.to_string(), "A: expected 4C, got 00");
+}
+
+#[test]
+fn every_differing_register_is_listed_in_transfer_order() {
+    let mut fc2 = ldx();
+    let actual = fc2.registers();
+    let expected = Registers {
+        x: 0x38,
+        p: 0xA4,
+        pc: actual.pc.wrapping_add(0x100),
+        ..actual
+    };
+    let error = check_state(&mut fc2, &expected).unwrap_err();
+    let CheckKind::State(diff) = error.kind else {
+        panic!("not a state mismatch: {:?}", error);
+    };
+    assert_eq!(
+        diff.to_string(),
+        format!(
+            "X: expected 38, got 39; P: expected N set, got N clear; PC: expected {:04X}, got {:04X}",
+            expected.pc, actual.pc
+        )
+    );
+}
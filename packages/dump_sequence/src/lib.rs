@@ -0,0 +1,4 @@
+//! Tools around the cpu trace logs in `dump_logs`.
+
+pub mod table;
+pub mod trace;
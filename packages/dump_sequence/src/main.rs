@@ -1,151 +1,138 @@
+use dump_sequence::table::checkpoint_table;
+use dump_sequence::trace::{self, TraceLine, NESTEST_LOG};
+use embassy_fc2_app::middleware::checkpoint::TableMode;
 use std::{
     env,
-    fs::{read_to_string, File},
-    io::Write,
-    path::Path,
+    fs::{self, read_to_string},
+    process,
 };
 
-#[derive(Debug, Clone)]
-struct RegisterStr {
-    pc_high_str: String,
-    pc_low_str: String,
-    a_str: String,
-    x_str: String,
-    y_str: String,
-    p_str: String,
-    s_str: String,
-}
-
-impl RegisterStr {
-    fn create_register_str(target_line_number: u32) -> Self {
-        let file_path = "../../dump_logs/nestest.log";
-        let binding = read_to_string(file_path).expect("File path need.");
-        let mut read_lines = binding.lines();
-        let target_line = read_lines.nth((target_line_number - 1) as usize);
+const USAGE: &str =
+    "usage: dump_sequence <line> [log] [--every <n> | --lines <a,b,...> | --bisect]";
 
-        fn gen_reg_str(o_str: Option<&str>, range_s: usize, range_e: usize) -> String {
-            o_str.unwrap()[range_s..range_e]
-                .to_uppercase()
-                .replace(' ', "0")
-        }
-
-        let pc_high_str = gen_reg_str(target_line, 0, 2);
-        let pc_low_str = gen_reg_str(target_line, 2, 4);
-        let a_str = gen_reg_str(target_line, 27, 29);
-        let x_str = gen_reg_str(target_line, 32, 34);
-        let y_str = gen_reg_str(target_line, 37, 39);
-        let p_str = gen_reg_str(target_line, 42, 44);
-        let s_str = gen_reg_str(target_line, 47, 49);
+/// Lines checked on the way to the target line, which is always checked last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Checkpoints {
+    Target,
+    Every(usize),
+    Lines(Vec<usize>),
+    /// Every line, searched for the first divergent one instead of checked in order.
+    Bisect,
+}
 
-        Self {
-            pc_high_str,
-            pc_low_str,
-            a_str,
-            x_str,
-            y_str,
-            p_str,
-            s_str,
-        }
+impl Checkpoints {
+    fn line_numbers(&self, target_line_number: usize) -> Vec<usize> {
+        let mut line_numbers = match self {
+            Checkpoints::Target => vec![],
+            Checkpoints::Every(n) => (1..target_line_number / n + 1).map(|i| i * n).collect(),
+            Checkpoints::Lines(lines) => lines.clone(),
+            Checkpoints::Bisect => (1..target_line_number).collect(),
+        };
+        line_numbers.retain(|&line_number| line_number < target_line_number);
+        line_numbers.push(target_line_number);
+        line_numbers.sort_unstable();
+        line_numbers.dedup();
+        line_numbers
     }
+}
 
-    fn gen_test_sequence_file(&self, target_line_number: u32) {
-        let path = Path::new("../stm32l476rg/src/bin/generated_sequence_test.rs");
-        let mut file = File::create(path).unwrap();
-        file.write_all(
-            b"#![no_std]
-#![no_main]
-
-use defmt::*;
-use embassy_fc2_app::middleware::mode::*;
-use embassy_stm32::dma::NoDma;
-use embassy_stm32::gpio::{Input, Level, Output, Pin, Pull, Speed};
-use embassy_stm32::usart::{BasicInstance, Config, Uart};
-use embassy_stm32::{bind_interrupts, peripherals, usart};
-use stm32l476rg::pin::util::*;
-use {defmt_rtt as _, panic_probe as _};
-
-bind_interrupts!(struct Irqs {
-    USART1 => usart::InterruptHandler<peripherals::USART1>;
-});
-
-pub fn jmp_c000<T: BasicInstance>(usart: &mut Uart<T>) {
-    usart_write(usart, &[OpeMode::Inst as u8, 0x4C, 0x00, 0xC0]);
-    check_valid_register_status(usart, TxReg::S, &[0xFD]);
-    check_valid_register_status(usart, TxReg::P, &[0b00100100]);
-    check_valid_register_status(usart, TxReg::PC, &[0x00, 0xC0]);
+struct Args {
+    target_line_number: usize,
+    log_path: String,
+    checkpoints: Checkpoints,
 }
 
-pub fn test_inst_sequence<T: BasicInstance, P: Pin, P2: Pin>(
-    usart: &mut Uart<T>,
-    nop: &Input<P>,
-    resb: &mut Output<P2>,
-) {
-    send_reset_signal_if_not_nop(&nop, resb);
-    usart_write(usart, &[CpuMode::DebugWithinInternalMemory as u8]);
-    usart_write(usart, &[CassetteMode::NesTest as u8]);
-    check_valid_register_status(usart, TxReg::S, &[0xFD]);
-    jmp_c000(usart);
-",
-        )
-        .unwrap();
-        for _ in 0..(target_line_number - 1) / 200 {
-            file.write_all(b"    usart_write(usart, &[OpeMode::Sequence as u8, 200]);\n")
-                .unwrap();
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut target_line_number = None;
+    let mut log_path = None;
+    let mut checkpoints = Checkpoints::Target;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--every" => {
+                let value = args.next().ok_or("--every needs a value")?;
+                match value.parse() {
+                    Ok(n) if n > 0 => checkpoints = Checkpoints::Every(n),
+                    _ => return Err(format!("invalid --every `{}`", value)),
+                }
+            }
+            "--bisect" => checkpoints = Checkpoints::Bisect,
+            "--lines" => {
+                let value = args.next().ok_or("--lines needs a value")?;
+                let lines = value
+                    .split(',')
+                    .map(|line| line.trim().parse::<usize>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| format!("invalid --lines `{}`", value))?;
+                checkpoints = Checkpoints::Lines(lines);
+            }
+            _ if target_line_number.is_none() => match arg.parse() {
+                Ok(n) if n > 0 => target_line_number = Some(n),
+                _ => return Err(format!("invalid line number `{}`", arg)),
+            },
+            _ if log_path.is_none() => log_path = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument `{}`", arg)),
         }
-
-        write!(
-            &mut file,
-            "
-    // step to {}
-    usart_write(usart, &[OpeMode::Sequence as u8, {}]);
-    check_valid_register_status(usart, TxReg::A, &[0x{}]);
-    check_valid_register_status(usart, TxReg::X, &[0x{}]);
-    check_valid_register_status(usart, TxReg::Y, &[0x{}]);
-    check_valid_register_status(usart, TxReg::P, &[0x{}]);
-    check_valid_register_status(usart, TxReg::S, &[0x{}]);
-    check_valid_register_status(usart, TxReg::PC, &[0x{}, 0x{}]);
-
-    info!(\"test_inst_sequence passed!\");
-}}
-",
-            target_line_number,
-            (target_line_number - 1) % 200,
-            self.a_str,
-            self.x_str,
-            self.y_str,
-            self.p_str,
-            self.s_str,
-            self.pc_low_str,
-            self.pc_high_str
-        )
-        .unwrap();
-
-        file.write_all(
-            b"
-#[cortex_m_rt::entry]
-fn main() -> ! {
-    let p = embassy_stm32::init(Default::default());
-    let config = Config::default();
-    let mut usart = Uart::new_with_rtscts(
-        p.USART1, p.PA10, p.PA9, Irqs, p.PA12, p.PA11, NoDma, NoDma, config,
-    )
-    .unwrap();
-    let _rw = Input::new(p.PA0, Pull::None);
-    let nop = Input::new(p.PA1, Pull::None);
-    let mut resb = Output::new(p.PA4, Level::Low, Speed::Medium);
-    test_inst_sequence(&mut usart, &nop, &mut resb);
-
-    info!(\"all tests passed!\");
-    loop {}
-}\n",
-        )
-        .unwrap();
     }
+    let target_line_number = target_line_number.ok_or("line number is missing")?;
+    if let Checkpoints::Lines(lines) = &checkpoints {
+        if let Some(line) = lines
+            .iter()
+            .find(|&&line| line == 0 || line > target_line_number)
+        {
+            return Err(format!(
+                "--lines `{}` is not between 1 and {}",
+                line, target_line_number
+            ));
+        }
+    }
+    Ok(Args {
+        target_line_number,
+        // either layout of nestest.log works, see `trace`.
+        log_path: log_path.unwrap_or_else(|| NESTEST_LOG.to_string()),
+        checkpoints,
+    })
+}
+
+/// Writes the table the `sequence` suite embeds when the firmware is built.
+fn gen_checkpoint_table(checkpoints: &[&TraceLine], mode: TableMode) {
+    let table = checkpoint_table(checkpoints, mode).unwrap_or_else(|e| panic!("{}", e));
+    fs::write("../fc2_suites/checkpoints.bin", table).unwrap();
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let target_line_number = args[1].parse::<u32>().unwrap();
-    let reg_s = RegisterStr::create_register_str(target_line_number);
-    reg_s.gen_test_sequence_file(target_line_number);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    let log = read_to_string(&args.log_path).expect("File path need.");
+    let lines = trace::parse(&log)
+        .take_while(|line| !matches!(line, Ok(line) if line.line_number > args.target_line_number))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|e| panic!("{}", e));
+    let checkpoints = args
+        .checkpoints
+        .line_numbers(args.target_line_number)
+        .into_iter()
+        .map(|line_number| {
+            lines
+                .binary_search_by_key(&line_number, |line| line.line_number)
+                .map(|index| &lines[index])
+                .unwrap_or_else(|_| panic!("line {} is not in the log.", line_number))
+        })
+        .collect::<Vec<_>>();
+    let mode = match args.checkpoints {
+        Checkpoints::Bisect => TableMode::Bisect,
+        _ => TableMode::InOrder,
+    };
+    gen_checkpoint_table(&checkpoints, mode);
+    if let Some(target) = checkpoints.last().and_then(|line| line.to_nestest()) {
+        println!(
+            "{} checkpoints up to line {}:",
+            checkpoints.len(),
+            args.target_line_number
+        );
+        println!("{}", target);
+    }
 }
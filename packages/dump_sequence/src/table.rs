@@ -0,0 +1,43 @@
+//! Checkpoint tables for the `sequence` suite, read back by
+//! `embassy_fc2_app::middleware::checkpoint`.
+
+use crate::trace::TraceLine;
+use embassy_fc2_app::middleware::checkpoint::{header, Checkpoint, TableMode};
+use std::fmt;
+
+/// More instructions separate two checkpoints than a record can count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManySteps {
+    pub line_number: usize,
+}
+
+impl fmt::Display for TooManySteps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "too many steps before line {}.", self.line_number)
+    }
+}
+
+impl std::error::Error for TooManySteps {}
+
+/// Table that checks `lines` in `mode`. It starts on line 1, where jmp_c000 leaves the cpu.
+pub fn checkpoint_table(lines: &[&TraceLine], mode: TableMode) -> Result<Vec<u8>, TooManySteps> {
+    let mut table = header(mode, lines.len() as u32).to_vec();
+    let mut line_number = 1;
+    for line in lines {
+        let steps = u16::try_from(line.line_number - line_number).map_err(|_| TooManySteps {
+            line_number: line.line_number,
+        })?;
+        line_number = line.line_number;
+        let mut instruction = [0x0u8; 3];
+        let bytes = line.instruction_bytes();
+        instruction[..bytes.len()].copy_from_slice(bytes);
+        let checkpoint = Checkpoint {
+            line: line.line_number as u32,
+            steps,
+            registers: line.registers,
+            instruction,
+        };
+        table.extend(checkpoint.to_bytes());
+    }
+    Ok(table)
+}
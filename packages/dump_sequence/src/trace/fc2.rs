@@ -0,0 +1,75 @@
+//! Layout of the bundled `dump_logs/nestest.log`.
+
+use super::{parse_addr_mode, parse_hex_u16, parse_hex_u8, ParseErrorKind, TraceLine};
+use embassy_fc2_app::cpu::Registers;
+
+// columns of the fixed width part. Everything after `S:` is `label: value` separated by `,`.
+const PC: (usize, usize) = (0, 4);
+const BYTES: [(usize, usize); 3] = [(5, 7), (8, 10), (11, 13)];
+const MNEMONIC: (usize, usize) = (14, 17);
+const ADDR_MODE: (usize, usize) = (18, 25);
+const REGISTERS: [(&str, usize); 5] = [("A:", 25), ("X:", 30), ("Y:", 35), ("P:", 40), ("S:", 45)];
+const FIXED_LEN: usize = 49;
+
+pub(super) fn parse(line_number: usize, line: &str) -> Result<TraceLine, ParseErrorKind> {
+    // columns are byte offsets, which only line up with characters in ASCII.
+    if !line.is_ascii() {
+        return Err(ParseErrorKind::NotAscii);
+    }
+    if line.len() < FIXED_LEN {
+        return Err(ParseErrorKind::TooShort { len: line.len() });
+    }
+    let column = |(s, e): (usize, usize)| line[s..e].trim();
+
+    let pc = parse_hex_u16("pc", column(PC))?;
+    let bytes = BYTES
+        .iter()
+        .map(|&range| parse_hex_u8("bytes", column(range)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mnemonic = column(MNEMONIC).to_string();
+    let addr_mode = parse_addr_mode(column(ADDR_MODE))?;
+
+    let mut values = [0u8; 5];
+    for (value, (label, start)) in values.iter_mut().zip(REGISTERS) {
+        if &line[start..start + 2] != label {
+            return Err(ParseErrorKind::MissingField(label));
+        }
+        *value = parse_hex_u8(label, line[start + 2..start + 4].trim())?;
+    }
+    let [a, x, y, p, s] = values;
+
+    let mut next_pc = None;
+    let mut reg_addr = None;
+    let mut cycle = None;
+    for field in line[FIXED_LEN..].split(',').map(str::trim) {
+        if field.is_empty() {
+            continue;
+        }
+        let Some((label, value)) = field.split_once(':') else {
+            return Err(ParseErrorKind::MissingField(":"));
+        };
+        let value = value.trim();
+        match label {
+            "pc" => next_pc = Some(parse_hex_u16("pc", value)?),
+            "reg_addr" => reg_addr = Some(parse_hex_u16("reg_addr", value)?),
+            "cycle" => {
+                cycle = Some(value.parse().map_err(|_| ParseErrorKind::InvalidNumber {
+                    field: "cycle",
+                    text: value.to_string(),
+                })?)
+            }
+            _ => {}
+        }
+    }
+
+    Ok(TraceLine {
+        line_number,
+        registers: Registers { a, x, y, s, p, pc },
+        bytes,
+        mnemonic,
+        addr_mode,
+        next_pc,
+        reg_addr,
+        cycle,
+    })
+}
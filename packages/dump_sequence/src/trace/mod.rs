@@ -0,0 +1,274 @@
+//! Parser for nestest trace logs.
+//!
+//! Each line is one executed instruction with the registers before it runs. Two layouts
+//! are read into the same [`TraceLine`], the one of `dump_logs/nestest.log`
+//!
+//! ```text
+//! c000 4c f5 c5 JMP  ABS   A: 0 X: 0 Y: 0 P:24 S:fd pc: c5f5, reg_addr: c5f5, cycle:     10
+//! ```
+//!
+//! and the one published with nestest, written by Nintendulator.
+//!
+//! ```text
+//! C000  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD PPU:  0, 21 CYC:7
+//! ```
+
+mod fc2;
+mod nintendulator;
+
+use embassy_fc2_app::cpu::Registers;
+use embassy_fc2_app::disasm::{self, Instruction};
+use embassy_fc2_app::middleware::mode::command::operand_len;
+use embassy_fc2_app::middleware::mode::AddrMode;
+use std::fmt;
+use std::path::Path;
+use std::str::Lines;
+
+/// Path of the nestest log, relative to a package directory.
+pub const NESTEST_LOG: &str = "../../dump_logs/nestest.log";
+
+/// One parsed line of a trace log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceLine {
+    /// 1-based line number in the log.
+    pub line_number: usize,
+    /// Registers before the instruction runs.
+    pub registers: Registers,
+    /// Bytes at `pc` as printed in the log. The fc2 layout always prints three, which can
+    /// run past the instruction itself.
+    pub bytes: Vec<u8>,
+    pub mnemonic: String,
+    pub addr_mode: AddrMode,
+    /// PC after the instruction.
+    pub next_pc: Option<u16>,
+    /// Effective address the instruction worked on. Only the fc2 layout has it.
+    pub reg_addr: Option<u16>,
+    /// Cycles elapsed when the instruction ends.
+    pub cycle: Option<u64>,
+}
+
+impl TraceLine {
+    pub fn opcode(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// Bytes that belong to the instruction itself.
+    pub fn instruction_bytes(&self) -> &[u8] {
+        let len = 1 + operand_len(self.opcode());
+        &self.bytes[..len.min(self.bytes.len())]
+    }
+
+    /// Short form such as `C000  4C F5 C5  JMP $C5F5`, used to point at a line in reports.
+    pub fn disassembly(&self) -> String {
+        let bytes = self
+            .instruction_bytes()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(" ");
+        match Instruction::decode(self.instruction_bytes()) {
+            Some(instruction) => format!(
+                "{:04X}  {:<8}  {}",
+                self.registers.pc,
+                bytes,
+                instruction.at(self.registers.pc)
+            ),
+            // a line cut short by the log still shows what it has.
+            None => format!(
+                "{:04X}  {:<8}  {} {:?}",
+                self.registers.pc, bytes, self.mnemonic, self.addr_mode
+            ),
+        }
+    }
+
+    /// The line in the Nintendulator layout, without `PPU` and `CYC`.
+    pub fn to_nestest(&self) -> Option<disasm::TraceLine> {
+        disasm::TraceLine::new(self.instruction_bytes(), self.registers, None)
+    }
+
+    /// Parses a single `line` of either layout, reporting errors at `line_number`.
+    ///
+    /// A Nintendulator line has no `next_pc` and `cycle` of its own. They are taken from
+    /// the line after it, so only [`parse`] fills them in.
+    pub fn parse(line_number: usize, line: &str) -> Result<Self, ParseError> {
+        parse_line(line_number, line).map(|parsed| parsed.line)
+    }
+}
+
+/// Layout of a trace log line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// `dump_logs/nestest.log`.
+    Fc2,
+    /// Reference log published with nestest.
+    Nintendulator,
+}
+
+impl Format {
+    pub fn detect(line: &str) -> Self {
+        if line.contains(" SP:") {
+            Format::Nintendulator
+        } else {
+            Format::Fc2
+        }
+    }
+}
+
+struct Parsed {
+    format: Format,
+    line: TraceLine,
+    // cycles elapsed when the instruction starts, only known for Nintendulator.
+    start_cycle: Option<u64>,
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<Parsed, ParseError> {
+    let format = Format::detect(line);
+    let parsed = match format {
+        Format::Fc2 => fc2::parse(line_number, line).map(|line| (line, None)),
+        Format::Nintendulator => nintendulator::parse(line_number, line)
+            .map(|(line, start_cycle)| (line, Some(start_cycle))),
+    };
+    parsed
+        .map(|(line, start_cycle)| Parsed {
+            format,
+            line,
+            start_cycle,
+        })
+        .map_err(|kind| ParseError { line_number, kind })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line ends before the register columns.
+    TooShort {
+        len: usize,
+    },
+    /// The columns are counted in bytes, so any other character would shift them.
+    NotAscii,
+    MissingField(&'static str),
+    InvalidHex {
+        field: &'static str,
+        text: String,
+    },
+    InvalidNumber {
+        field: &'static str,
+        text: String,
+    },
+    UnknownAddrMode(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line_number: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line_number)?;
+        match &self.kind {
+            ParseErrorKind::TooShort { len } => write!(f, "line is too short ({} chars)", len),
+            ParseErrorKind::NotAscii => write!(f, "line has non-ASCII characters"),
+            ParseErrorKind::MissingField(field) => write!(f, "missing `{}`", field),
+            ParseErrorKind::InvalidHex { field, text } => {
+                write!(f, "invalid hex `{}` for {}", text, field)
+            }
+            ParseErrorKind::InvalidNumber { field, text } => {
+                write!(f, "invalid number `{}` for {}", text, field)
+            }
+            ParseErrorKind::UnknownAddrMode(text) => {
+                write!(f, "unknown addressing mode `{}`", text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Iterator over the lines of a trace log. Blank lines are skipped.
+pub struct Trace<'a> {
+    lines: Lines<'a>,
+    line_number: usize,
+    peeked: Option<Result<Parsed, ParseError>>,
+}
+
+impl Trace<'_> {
+    fn next_parsed(&mut self) -> Option<Result<Parsed, ParseError>> {
+        loop {
+            let line = self.lines.next()?;
+            self.line_number += 1;
+            if !line.trim().is_empty() {
+                return Some(parse_line(self.line_number, line));
+            }
+        }
+    }
+}
+
+impl Iterator for Trace<'_> {
+    type Item = Result<TraceLine, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut parsed = match self.peeked.take().or_else(|| self.next_parsed())? {
+            Ok(parsed) => parsed,
+            Err(e) => return Some(Err(e)),
+        };
+        if parsed.format == Format::Nintendulator {
+            self.peeked = self.next_parsed();
+            if let Some(Ok(next)) = &self.peeked {
+                parsed.line.next_pc = Some(next.line.registers.pc);
+                parsed.line.cycle = next.start_cycle;
+            }
+        }
+        Some(Ok(parsed.line))
+    }
+}
+
+/// Iterates the lines of `log` lazily. The layout is detected per line.
+pub fn parse(log: &str) -> Trace<'_> {
+    Trace {
+        lines: log.lines(),
+        line_number: 0,
+        peeked: None,
+    }
+}
+
+/// Reads and parses a whole log, stopping at the first malformed line.
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<TraceLine>, Box<dyn std::error::Error>> {
+    let log = std::fs::read_to_string(path)?;
+    Ok(parse(&log).collect::<Result<_, _>>()?)
+}
+
+fn parse_hex_u8(field: &'static str, text: &str) -> Result<u8, ParseErrorKind> {
+    u8::from_str_radix(text, 16).map_err(|_| ParseErrorKind::InvalidHex {
+        field,
+        text: text.to_string(),
+    })
+}
+
+fn parse_hex_u16(field: &'static str, text: &str) -> Result<u16, ParseErrorKind> {
+    u16::from_str_radix(text, 16).map_err(|_| ParseErrorKind::InvalidHex {
+        field,
+        text: text.to_string(),
+    })
+}
+
+fn parse_addr_mode(text: &str) -> Result<AddrMode, ParseErrorKind> {
+    let addr_mode = match text {
+        "ACC" => AddrMode::Acc,
+        "IMM" => AddrMode::Imm,
+        "ABS" => AddrMode::Abs,
+        "ABSX" => AddrMode::AbsX,
+        "ABSY" => AddrMode::AbsY,
+        "ZP" => AddrMode::Zp,
+        "ZPX" => AddrMode::ZpX,
+        "ZPY" => AddrMode::ZpY,
+        "IMPL" => AddrMode::Impl,
+        "REL" => AddrMode::Rel,
+        "INDX" => AddrMode::IndX,
+        "INDY" => AddrMode::IndY,
+        "IND" => AddrMode::Ind,
+        "NOP" => AddrMode::Nop,
+        _ => return Err(ParseErrorKind::UnknownAddrMode(text.to_string())),
+    };
+    Ok(addr_mode)
+}
@@ -0,0 +1,79 @@
+//! Layout of the reference log published with nestest.
+//!
+//! Unofficial opcodes have their mnemonic prefixed with `*`, and the addressing mode is
+//! only visible through the disassembly, so it is looked up from the opcode instead.
+
+use super::{parse_hex_u16, parse_hex_u8, ParseErrorKind, TraceLine};
+use embassy_fc2_app::cpu::Registers;
+use embassy_fc2_app::operators::OPERATORS;
+
+const PC: (usize, usize) = (0, 4);
+const BYTES: (usize, usize) = (6, 14);
+const MNEMONIC: usize = 15;
+const REGISTERS: [&str; 5] = ["A:", "X:", "Y:", "P:", "SP:"];
+
+/// Parses `line` and returns it with the cycles elapsed before the instruction.
+pub(super) fn parse(line_number: usize, line: &str) -> Result<(TraceLine, u64), ParseErrorKind> {
+    if !line.is_ascii() {
+        return Err(ParseErrorKind::NotAscii);
+    }
+    let Some(registers_start) = line.find(" A:") else {
+        return Err(ParseErrorKind::MissingField("A:"));
+    };
+    if registers_start < MNEMONIC || line.len() < BYTES.1 {
+        return Err(ParseErrorKind::TooShort { len: line.len() });
+    }
+
+    let pc = parse_hex_u16("pc", line[PC.0..PC.1].trim())?;
+    let bytes = line[BYTES.0..BYTES.1]
+        .split_whitespace()
+        .map(|text| parse_hex_u8("bytes", text))
+        .collect::<Result<Vec<_>, _>>()?;
+    let Some(&opcode) = bytes.first() else {
+        return Err(ParseErrorKind::MissingField("bytes"));
+    };
+    let mnemonic = line[MNEMONIC..registers_start]
+        .split_whitespace()
+        .next()
+        .ok_or(ParseErrorKind::MissingField("mnemonic"))?
+        .trim_start_matches('*')
+        .to_string();
+
+    let fields = &line[registers_start..];
+    let mut values = [0u8; 5];
+    for (value, label) in values.iter_mut().zip(REGISTERS) {
+        *value = parse_hex_u8(label, field(fields, label)?)?;
+    }
+    let [a, x, y, p, s] = values;
+    let cycle = field(fields, "CYC:")?;
+    let cycle = cycle.parse().map_err(|_| ParseErrorKind::InvalidNumber {
+        field: "CYC:",
+        text: cycle.to_string(),
+    })?;
+
+    let line = TraceLine {
+        line_number,
+        registers: Registers { a, x, y, s, p, pc },
+        bytes,
+        mnemonic,
+        addr_mode: OPERATORS[opcode as usize].addr_mode,
+        next_pc: None,
+        reg_addr: None,
+        cycle: None,
+    };
+    Ok((line, cycle))
+}
+
+/// Value right after ` label`, up to the next space.
+fn field<'a>(fields: &'a str, label: &'static str) -> Result<&'a str, ParseErrorKind> {
+    fields
+        .split_whitespace()
+        .find_map(|token| token.strip_prefix(label))
+        .filter(|value| !value.is_empty())
+        .or_else(|| {
+            // `CYC:  7` in older logs pads the value with spaces.
+            let start = fields.find(label)? + label.len();
+            fields[start..].split_whitespace().next()
+        })
+        .ok_or(ParseErrorKind::MissingField(label))
+}
@@ -0,0 +1,33 @@
+use dump_sequence::trace::{self, NESTEST_LOG};
+use embassy_fc2_app::cpu::bus::FlatMemory;
+use embassy_fc2_app::cpu::{Cpu, Registers};
+
+const NESTEST_MIF: &str = "../../hex_init_files/nestest.mif";
+
+/// Lines whose registers the reference cpu does not reproduce. The INC on the line before
+/// each of them clears V in the log, which INC leaves alone on a 6502.
+const DIVERGENT_LINES: [usize; 2] = [3289, 3592];
+
+#[test]
+fn reference_cpu_follows_nestest_log() {
+    let source = std::fs::read_to_string(NESTEST_MIF).unwrap();
+    let prg = mif::Mif::parse(&source).unwrap().to_bytes().unwrap();
+    let mut memory = Box::new([0x0u8; 0x10000]);
+    memory[0x8000..0xC000].copy_from_slice(&prg);
+    memory[0xC000..].copy_from_slice(&prg);
+    let mut bus = FlatMemory::new(&mut memory);
+
+    let lines = trace::load(NESTEST_LOG).unwrap();
+    let mut cpu = Cpu::with_registers(lines[0].registers);
+    let mut mismatches: Vec<(usize, Registers, Registers)> = vec![];
+    for line in &lines[1..] {
+        cpu.step(&mut bus);
+        if cpu.registers != line.registers {
+            mismatches.push((line.line_number, line.registers, cpu.registers));
+            // carry on from the log so that one divergence is reported once.
+            cpu.registers = line.registers;
+        }
+    }
+    let divergent_lines: Vec<usize> = mismatches.iter().map(|m| m.0).collect();
+    assert_eq!(divergent_lines, DIVERGENT_LINES, "{:#04X?}", mismatches);
+}
@@ -0,0 +1,38 @@
+use dump_sequence::table::{checkpoint_table, TooManySteps};
+use dump_sequence::trace::{self, NESTEST_LOG};
+use embassy_fc2_app::middleware::checkpoint::{CheckpointTable, TableMode};
+
+#[test]
+fn table_round_trips_through_the_firmware_reader() {
+    let lines = trace::load(NESTEST_LOG).unwrap();
+    let checkpoints: Vec<_> = lines.iter().step_by(97).skip(1).collect();
+    for mode in [TableMode::InOrder, TableMode::Bisect] {
+        let bytes = checkpoint_table(&checkpoints, mode).unwrap();
+        let table = CheckpointTable::parse(&bytes).unwrap();
+        assert_eq!(table.mode(), mode);
+        assert_eq!(table.len(), checkpoints.len());
+        let mut line_number = 1;
+        for (checkpoint, line) in table.iter().zip(&checkpoints) {
+            assert_eq!(checkpoint.line as usize, line.line_number);
+            assert_eq!(checkpoint.steps as usize, line.line_number - line_number);
+            assert_eq!(checkpoint.registers, line.registers);
+            assert_eq!(checkpoint.instruction(), line.instruction_bytes());
+            line_number = line.line_number;
+        }
+    }
+}
+
+#[test]
+fn table_rejects_gaps_a_record_cannot_count() {
+    let lines = trace::load(NESTEST_LOG).unwrap();
+    let mut far = lines[1].clone();
+    far.line_number = 2 + u16::MAX as usize;
+    assert!(checkpoint_table(&[&lines[1], &far], TableMode::InOrder).is_ok());
+    far.line_number += 1;
+    assert_eq!(
+        checkpoint_table(&[&lines[1], &far], TableMode::InOrder),
+        Err(TooManySteps {
+            line_number: far.line_number
+        })
+    );
+}
@@ -0,0 +1,140 @@
+use dump_sequence::trace::{self, ParseError, ParseErrorKind, TraceLine};
+use embassy_fc2_app::cpu::Registers;
+use embassy_fc2_app::middleware::mode::AddrMode;
+
+const FC2_LOG: &str = "\
+c000 4c f5 c5 JMP  ABS   A: 0 X: 0 Y: 0 P:24 S:fd pc: c5f5, reg_addr: c5f5, cycle:     10
+c5f5 a2  0 86 LDX  IMM   A: 0 X: 0 Y: 0 P:24 S:fd pc: c5f7, reg_addr:    0, cycle:     12
+";
+
+const NINTENDULATOR_LOG: &str = "\
+C000  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD PPU:  0, 21 CYC:7
+C5F5  A2 00     LDX #$00                        A:00 X:00 Y:00 P:24 SP:FD PPU:  0, 30 CYC:10
+C72C  04 A9    *NOP $A9 = 00                    A:AA X:97 Y:4E P:EF SP:F9 PPU: 91,  5 CYC:  12
+";
+
+fn parse_all(log: &str) -> Result<Vec<TraceLine>, ParseError> {
+    trace::parse(log).collect()
+}
+
+#[test]
+fn parses_fc2_layout() {
+    let lines = parse_all(FC2_LOG).unwrap();
+    assert_eq!(lines.len(), 2);
+    let line = &lines[1];
+    assert_eq!(line.line_number, 2);
+    assert_eq!(
+        line.registers,
+        Registers {
+            a: 0x00,
+            x: 0x00,
+            y: 0x00,
+            s: 0xFD,
+            p: 0x24,
+            pc: 0xC5F5,
+        }
+    );
+    assert_eq!(line.bytes, [0xA2, 0x00, 0x86]);
+    assert_eq!(line.instruction_bytes(), [0xA2, 0x00]);
+    assert_eq!(line.mnemonic, "LDX");
+    assert_eq!(line.addr_mode, AddrMode::Imm);
+    assert_eq!(line.next_pc, Some(0xC5F7));
+    assert_eq!(line.reg_addr, Some(0x0000));
+    assert_eq!(line.cycle, Some(12));
+}
+
+#[test]
+fn fc2_errors_name_their_line() {
+    let log = format!(
+        "{}\nc5f7 86  0 86 STX  ZP    A: 0 X: 0 Y: 0 P:2g S:fd\n",
+        FC2_LOG
+    );
+    let error = parse_all(&log).unwrap_err();
+    assert_eq!(error.line_number, 4);
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::InvalidHex {
+            field: "P:",
+            text: "2g".to_string()
+        }
+    );
+    assert!(error.to_string().starts_with("line 4: "));
+
+    let error = TraceLine::parse(7, "c000 4c f5 c5 JMP").unwrap_err();
+    assert_eq!(error.line_number, 7);
+    assert_eq!(error.kind, ParseErrorKind::TooShort { len: 17 });
+}
+
+#[test]
+fn fc2_rejects_non_ascii() {
+    for line in [
+        "c000 4c f5 c5 JMP  ABS   A: 0 X: 0 Y: 0 P:24 S:fé pc: c5f5, reg_addr: c5f5, cycle: 10",
+        "c000 4c f5 c5 JMP  ABS   A: 0 X: 0 Y: 0 P:24 S:fd pc: c5f5, reg_addr: c5f5, cycle: ①",
+        "c000 4c f5 c5 JMP  ÄBS   A: 0 X: 0 Y: 0 P:24 S:fd",
+    ] {
+        let error = TraceLine::parse(1, line).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::NotAscii, "{}", line);
+    }
+}
+
+#[test]
+fn parses_nintendulator_layout() {
+    let lines = parse_all(NINTENDULATOR_LOG).unwrap();
+    assert_eq!(lines.len(), 3);
+    let line = &lines[1];
+    assert_eq!(line.line_number, 2);
+    assert_eq!(
+        line.registers,
+        Registers {
+            a: 0x00,
+            x: 0x00,
+            y: 0x00,
+            s: 0xFD,
+            p: 0x24,
+            pc: 0xC5F5,
+        }
+    );
+    assert_eq!(line.bytes, [0xA2, 0x00]);
+    assert_eq!(line.mnemonic, "LDX");
+    assert_eq!(line.addr_mode, AddrMode::Imm);
+    // taken from the line after it.
+    assert_eq!(line.next_pc, Some(0xC72C));
+    assert_eq!(line.cycle, Some(12));
+    assert_eq!(line.reg_addr, None);
+
+    let unofficial = &lines[2];
+    assert_eq!(unofficial.mnemonic, "NOP");
+    assert_eq!(unofficial.addr_mode, AddrMode::Zp);
+    assert_eq!(unofficial.next_pc, None);
+    assert_eq!(unofficial.cycle, None);
+}
+
+#[test]
+fn nintendulator_errors_name_their_line() {
+    let log = NINTENDULATOR_LOG.replace("CYC:10", "CYC:1O");
+    let error = parse_all(&log).unwrap_err();
+    assert_eq!(error.line_number, 2);
+    assert_eq!(
+        error.kind,
+        ParseErrorKind::InvalidNumber {
+            field: "CYC:",
+            text: "1O".to_string()
+        }
+    );
+
+    let error = TraceLine::parse(5, "C000  4C F5 C5  JMP $C5F5").unwrap_err();
+    assert_eq!(error.line_number, 5);
+}
+
+#[test]
+fn nintendulator_rejects_non_ascii() {
+    for line in [
+        "C000  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD PPU:  0, 21 CYC:7é",
+        "C0é  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD CYC:7",
+        "C000  4C F5 Ç5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD CYC:7",
+    ] {
+        let error = TraceLine::parse(9, line).unwrap_err();
+        assert_eq!(error.line_number, 9);
+        assert_eq!(error.kind, ParseErrorKind::NotAscii, "{}", line);
+    }
+}
@@ -0,0 +1,12 @@
+use embassy_fc2_app::middleware::checkpoint::{header, TableMode};
+use std::{env, fs, path::Path};
+
+fn main() {
+    // table written by dump_sequence, or an empty one so that the suites always build.
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=checkpoints.bin");
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("checkpoints.bin");
+    let table =
+        fs::read("checkpoints.bin").unwrap_or_else(|_| header(TableMode::InOrder, 0).to_vec());
+    fs::write(out, table).unwrap();
+}
@@ -0,0 +1,191 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_bit_zp_without_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x01, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x3A])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x3A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x3A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bit_zp_with_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x01, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_bit_zp_with_nv_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x01, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    Ok(())
+}
+
+pub fn test_bit_zp_with_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x01, 0x00])?)?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_bit_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xC0])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xC0])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x05])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x05])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x2C, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x05])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100110])?;
+    Ok(())
+}
+
+pub fn test_bit_zp_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x3A])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x3A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x01, 0x00])?;
+    usart_write(fc2, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x3A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bit_zp_with_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x01, 0x00])?;
+    usart_write(fc2, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_bit_zp_with_nv_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x01, 0x00])?;
+    usart_write(fc2, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    Ok(())
+}
+
+pub fn test_bit_zp_with_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x24, &[0x01])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x01, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_bit_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x05])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x05])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x2C, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0xC0])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x05])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100110])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_bit_zp_without_flag_within_internal_memory);
+    run_test!(runner, fc2, test_bit_zp_with_n_within_internal_memory);
+    run_test!(runner, fc2, test_bit_zp_with_nv_within_internal_memory);
+    run_test!(runner, fc2, test_bit_zp_with_z_within_internal_memory);
+    run_test!(runner, fc2, test_bit_abs_within_internal_memory);
+    run_test!(runner, fc2, test_bit_zp_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_bit_zp_with_n_within_mocking_memory);
+    run_test!(runner, fc2, test_bit_zp_with_nv_within_mocking_memory);
+    run_test!(runner, fc2, test_bit_zp_with_z_within_mocking_memory);
+    run_test!(runner, fc2, test_bit_abs_within_mocking_memory);
+}
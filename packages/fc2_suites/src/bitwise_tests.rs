@@ -0,0 +1,1169 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_ora_indx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x80, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x81, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x02])?)?;
+    send_command(fc2, Command::inst(0x01, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x54])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x54])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x05, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFE])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_imm_without_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x09, &[0x10])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x10])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ora_imm_with_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x09, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_ora_imm_with_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x09, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0x0D, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_ora_indy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x33, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x04])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x34, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x11, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x15, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ora_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x19, &[0x00, 0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x1D, &[0x00, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_indx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x82, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x03])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x03])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x83, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xEF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x03])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x21, &[0x82])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_and_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x25, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_imm_without_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x6F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x6F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x29, &[0xEF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x6F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_and_imm_with_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x29, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_imm_with_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x67])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x67])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x29, &[0x98])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xEF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    send_command(fc2, Command::inst(0x2D, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_and_indy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x33, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x04])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x34])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x31, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xEF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x35, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_and_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x39, &[0x00, 0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xEF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x3D, &[0x00, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_indx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x80, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x81, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x02])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x41, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x45, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_imm_without_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xDF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xDF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x49, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x75])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_eor_imm_with_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x49, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_imm_with_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x49, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x4D, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_indy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x33, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x04])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x34, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x51, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x55, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x59, &[0x00, 0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x5D, &[0x00, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_indx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x01, &[0x80])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x80, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x81, 0x00])?;
+    usart_write(fc2, &[0x02])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x02])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x54])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x54])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x05, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFE])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_imm_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x09, &[0x10])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x10])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ora_imm_with_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x09, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_ora_imm_with_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x09, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x0D, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_ora_indy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x11, &[0x33])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x33, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x34, 0x00])?;
+    usart_write(fc2, &[0x04])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x15, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ora_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x19, &[0x00, 0x04])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ora_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x1D, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_indx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x21, &[0x82])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x82, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x83, 0x00])?;
+    usart_write(fc2, &[0x03])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x03])?;
+    usart_write(fc2, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_and_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x25, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_imm_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x6F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x6F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x29, &[0xEF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x6F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_and_imm_with_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x29, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_imm_with_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x67])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x67])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x29, &[0x98])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    send_command(fc2, Command::inst(0x2D, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_and_indy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x31, &[0x33])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x33, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x34, 0x00])?;
+    usart_write(fc2, &[0x04])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x35, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_and_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x39, &[0x00, 0x04])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_and_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xF8])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x3D, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0xEF])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xE8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_indx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x41, &[0x80])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x80, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x81, 0x00])?;
+    usart_write(fc2, &[0x02])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x02])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x45, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_imm_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xDF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xDF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x49, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x75])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_eor_imm_with_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x49, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_imm_with_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x49, &[0xAA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x4D, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_indy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x51, &[0x33])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x33, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x34, 0x00])?;
+    usart_write(fc2, &[0x04])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x55, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_eor_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x70])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x59, &[0x00, 0x04])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0x70])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_eor_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x5F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x5F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x5D, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_ora_indx_within_internal_memory);
+    run_test!(runner, fc2, test_ora_zp_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_ora_imm_without_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_ora_imm_with_z_within_internal_memory);
+    run_test!(runner, fc2, test_ora_imm_with_n_within_internal_memory);
+    run_test!(runner, fc2, test_ora_abs_within_internal_memory);
+    run_test!(runner, fc2, test_ora_indy_within_internal_memory);
+    run_test!(runner, fc2, test_ora_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_ora_absy_within_internal_memory);
+    run_test!(runner, fc2, test_ora_absx_within_internal_memory);
+    run_test!(runner, fc2, test_and_indx_within_internal_memory);
+    run_test!(runner, fc2, test_and_zp_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_and_imm_without_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_and_imm_with_z_within_internal_memory);
+    run_test!(runner, fc2, test_and_imm_with_n_within_internal_memory);
+    run_test!(runner, fc2, test_and_abs_within_internal_memory);
+    run_test!(runner, fc2, test_and_indy_within_internal_memory);
+    run_test!(runner, fc2, test_and_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_and_absy_within_internal_memory);
+    run_test!(runner, fc2, test_and_absx_within_internal_memory);
+    run_test!(runner, fc2, test_eor_indx_within_internal_memory);
+    run_test!(runner, fc2, test_eor_zp_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_eor_imm_without_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_eor_imm_with_z_within_internal_memory);
+    run_test!(runner, fc2, test_eor_imm_with_n_within_internal_memory);
+    run_test!(runner, fc2, test_eor_abs_within_internal_memory);
+    run_test!(runner, fc2, test_eor_indy_within_internal_memory);
+    run_test!(runner, fc2, test_eor_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_eor_absy_within_internal_memory);
+    run_test!(runner, fc2, test_eor_absx_within_internal_memory);
+    run_test!(runner, fc2, test_ora_indx_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_imm_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_imm_with_z_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_imm_with_n_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_indy_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_ora_absx_within_mocking_memory);
+    run_test!(runner, fc2, test_and_indx_within_mocking_memory);
+    run_test!(runner, fc2, test_and_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_and_imm_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_and_imm_with_z_within_mocking_memory);
+    run_test!(runner, fc2, test_and_imm_with_n_within_mocking_memory);
+    run_test!(runner, fc2, test_and_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_and_indy_within_mocking_memory);
+    run_test!(runner, fc2, test_and_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_and_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_and_absx_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_indx_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_imm_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_imm_with_z_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_imm_with_n_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_indy_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_eor_absx_within_mocking_memory);
+}
@@ -0,0 +1,668 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_bpl_rel_condition_neg_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xB2, 0xC7])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xB2, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x10, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xB8, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bpl_rel_condition_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xBB, 0xC7])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xBB, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x10, &[0x03])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xBD, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_bmi_rel_condition_neg_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0x3C, 0xC8])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x3C, 0xC8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x30, &[0x07])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x3E, 0xC8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bmi_rel_condition_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0x9B, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x9B, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x30, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x9F, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+
+    Ok(())
+}
+
+pub fn test_bvc_rel_condition_neg_v_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xC5, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC5, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x50, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bvc_rel_condition_v_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x69, &[0x1])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xA9, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xA9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x50, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAB, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+
+    Ok(())
+}
+
+pub fn test_bvs_rel_condition_neg_v_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xEE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xEE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x70, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF0, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bvs_rel_condition_v_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x69, &[0x1])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x70, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE2, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+
+    Ok(())
+}
+
+pub fn test_bcc_rel_condition_neg_c_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xC5, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC5, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x90, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bcc_rel_condition_c_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x4C, &[0xA9, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xA9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x90, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAB, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+
+    Ok(())
+}
+
+pub fn test_bcs_rel_condition_neg_c_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xB0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE0, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bcs_rel_condition_c_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xB0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE2, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_bne_rel_condition_neg_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xC5, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC5, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xD0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bne_rel_condition_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x18, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0x4C, &[0xA9, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xA9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0xD0, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAB, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+
+    Ok(())
+}
+
+pub fn test_beq_rel_condition_neg_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xF0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE0, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_beq_rel_condition_z_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x18, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0xF0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE2, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_bpl_rel_condition_neg_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xB2, 0xC7])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xB2, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x10, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xB8, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bpl_rel_condition_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xBB, 0xC7])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xBB, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x10, &[0x03])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xBD, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_bmi_rel_condition_neg_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0x3C, 0xC8])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x3C, 0xC8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x30, &[0x07])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x3E, 0xC8])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bmi_rel_condition_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0x9B, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x9B, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x30, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x9F, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+
+    Ok(())
+}
+
+pub fn test_bvc_rel_condition_neg_v_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xC5, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC5, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x50, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bvc_rel_condition_v_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x69, &[0x1])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xA9, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xA9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x50, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAB, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+
+    Ok(())
+}
+
+pub fn test_bvs_rel_condition_neg_v_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xEE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xEE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x70, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF0, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bvs_rel_condition_v_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x69, &[0x1])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0x70, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE2, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+
+    Ok(())
+}
+
+pub fn test_bcc_rel_condition_neg_c_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xC5, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC5, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x90, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bcc_rel_condition_c_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x4C, &[0xA9, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xA9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x90, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAB, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+
+    Ok(())
+}
+
+pub fn test_bcs_rel_condition_neg_c_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xB0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE0, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bcs_rel_condition_c_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xB0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE2, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_bne_rel_condition_neg_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xC5, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC5, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xD0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xC9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_bne_rel_condition_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x18, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0x4C, &[0xA9, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xA9, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0xD0, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAB, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+
+    Ok(())
+}
+
+pub fn test_beq_rel_condition_neg_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xF0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE0, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_beq_rel_condition_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x18, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0x4C, &[0xDE, 0xD9])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xDE, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0xF0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xE2, 0xD9])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(
+        runner,
+        fc2,
+        test_bpl_rel_condition_neg_n_within_internal_memory
+    );
+    run_test!(runner, fc2, test_bpl_rel_condition_n_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bmi_rel_condition_neg_n_within_internal_memory
+    );
+    run_test!(runner, fc2, test_bmi_rel_condition_n_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bvc_rel_condition_neg_v_within_internal_memory
+    );
+    run_test!(runner, fc2, test_bvc_rel_condition_v_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bvs_rel_condition_neg_v_within_internal_memory
+    );
+    run_test!(runner, fc2, test_bvs_rel_condition_v_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bcc_rel_condition_neg_c_within_internal_memory
+    );
+    run_test!(runner, fc2, test_bcc_rel_condition_c_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bcs_rel_condition_neg_c_within_internal_memory
+    );
+    run_test!(runner, fc2, test_bcs_rel_condition_c_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bne_rel_condition_neg_z_within_internal_memory
+    );
+    run_test!(runner, fc2, test_bne_rel_condition_z_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_beq_rel_condition_neg_z_within_internal_memory
+    );
+    run_test!(runner, fc2, test_beq_rel_condition_z_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bpl_rel_condition_neg_n_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_bpl_rel_condition_n_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bmi_rel_condition_neg_n_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_bmi_rel_condition_n_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bvc_rel_condition_neg_v_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_bvc_rel_condition_v_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bvs_rel_condition_neg_v_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_bvs_rel_condition_v_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bcc_rel_condition_neg_c_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_bcc_rel_condition_c_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bcs_rel_condition_neg_c_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_bcs_rel_condition_c_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_bne_rel_condition_neg_z_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_bne_rel_condition_z_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_beq_rel_condition_neg_z_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_beq_rel_condition_z_within_mocking_memory);
+}
@@ -0,0 +1,169 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_brk_impl_without_b_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xC6])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xC6])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFE, 0xFF])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFF, 0xFF])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xF5, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x00, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x40, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xC5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFC, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF6])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110100])?;
+
+    Ok(())
+}
+
+pub fn test_brk_impl_with_b_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xC6])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xC6])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFE, 0xFF])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFF, 0xFF])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xF5, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x00, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x40, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110100])?;
+    send_command(fc2, Command::inst(0x58, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x41, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110000])?;
+    send_command(fc2, Command::inst(0x00, &[])?)?;
+    // check_valid_register_status(fc2, TxReg::PC, &[0x42, 0xC6]);
+    // check_valid_register_status(fc2, TxReg::S, &[0xFA]);
+    // check_valid_register_status(fc2, TxReg::P, &[0b10110000]);
+    // send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?);
+    // check_valid_register_status(fc2, TxReg::A, &[0xC5]);
+    // check_valid_register_status(fc2, TxReg::P, &[0b10110000]);
+    // send_command(fc2, Command::inst(0xAD, &[0xFC, 0x01])?);
+    // check_valid_register_status(fc2, TxReg::A, &[0xF5]);
+    // check_valid_register_status(fc2, TxReg::P, &[0b10110000]);
+    // send_command(fc2, Command::inst(0xAD, &[0xFB, 0x01])?);
+    // check_valid_register_status(fc2, TxReg::A, &[0b10100100]);
+    // check_valid_register_status(fc2, TxReg::P, &[0b10110000]);
+    Ok(())
+}
+
+pub fn test_brk_impl_without_b_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xF5, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x00, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0xC5])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFC, 0x01, 0xF6])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFB, 0x01, 0b10100100])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFE, 0xFF])?;
+    usart_write(fc2, &[0xC6])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFF, 0xFF])?;
+    usart_write(fc2, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x40, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110100])?;
+    Ok(())
+}
+
+pub fn test_brk_impl_with_b_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x4C, &[0xF5, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x00, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0xC5])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFC, 0x01, 0xF6])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFB, 0x01, 0b10100100])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFE, 0xFF])?;
+    usart_write(fc2, &[0xC6])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFF, 0xFF])?;
+    usart_write(fc2, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x40, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110100])?;
+    send_command(fc2, Command::inst(0x58, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x41, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10110000])?;
+    send_command(fc2, Command::inst(0x00, &[])?)?;
+    // check_valid_register_status(fc2, TxReg::PC, &[0x42, 0xC6]);
+    // check_valid_register_status(fc2, TxReg::S, &[0xFA]);
+    // check_valid_register_status(fc2, TxReg::P, &[0b10110000]);
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(
+        runner,
+        fc2,
+        test_brk_impl_without_b_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_brk_impl_with_b_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_brk_impl_without_b_flag_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_brk_impl_with_b_flag_within_mocking_memory);
+}
@@ -0,0 +1,136 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_clc_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x38, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0x18, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_cld_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xF8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00101100])?;
+    send_command(fc2, Command::inst(0xD8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_cli_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x78, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x58, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100000])?;
+    Ok(())
+}
+
+pub fn test_clv_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x69, &[0x1])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0xB8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_clc_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x38, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0x18, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_cld_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xF8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00101100])?;
+    send_command(fc2, Command::inst(0xD8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_cli_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x78, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x58, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100000])?;
+    Ok(())
+}
+
+pub fn test_clv_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x69, &[0x1])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b11100100])?;
+    send_command(fc2, Command::inst(0xB8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_clc_impl_within_internal_memory);
+    run_test!(runner, fc2, test_cld_impl_within_internal_memory);
+    run_test!(runner, fc2, test_cli_impl_within_internal_memory);
+    run_test!(runner, fc2, test_clv_impl_within_internal_memory);
+    run_test!(runner, fc2, test_clc_impl_within_mocking_memory);
+    run_test!(runner, fc2, test_cld_impl_within_mocking_memory);
+    run_test!(runner, fc2, test_cli_impl_within_mocking_memory);
+    run_test!(runner, fc2, test_clv_impl_within_mocking_memory);
+}
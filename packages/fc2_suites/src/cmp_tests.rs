@@ -0,0 +1,331 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_cmp_indx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0x8D, &[0x80, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x81, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x41])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x02])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC1, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_cmp_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xC5, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_cmp_imm_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x6F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x6F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x6F])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xCD, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_indy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x33, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x04])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x34, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x02, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xD1, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xD5, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_cmp_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xD9, &[0x00, 0x04])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x41])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xDD, &[0x00, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_cmp_indx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC1, &[0x80])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x80, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x81, 0x00])?;
+    usart_write(fc2, &[0x02])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x02])?;
+    usart_write(fc2, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_cmp_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xC5, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_cmp_imm_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x6F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x6F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x6F])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xCD, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_indy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xD1, &[0x33])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x33, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x34, 0x00])?;
+    usart_write(fc2, &[0x04])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x02, 0x04])?;
+    usart_write(fc2, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xD5, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_cmp_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xD9, &[0x00, 0x04])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_cmp_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xDD, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_cmp_indx_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_zp_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_imm_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_abs_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_indy_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_absy_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_absx_within_internal_memory);
+    run_test!(runner, fc2, test_cmp_indx_within_mocking_memory);
+    run_test!(runner, fc2, test_cmp_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_cmp_imm_within_mocking_memory);
+    run_test!(runner, fc2, test_cmp_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_cmp_indy_within_mocking_memory);
+    run_test!(runner, fc2, test_cmp_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_cmp_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_cmp_absx_within_mocking_memory);
+}
@@ -0,0 +1,433 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_dey_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x32])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dey_impl_with_rising_z_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x01])?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_dey_impl_with_rising_n_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dey_impl_with_falling_n_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x32])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_with_rising_z_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x01])?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_with_rising_n_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_with_falling_n_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dec_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xC6, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x78, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dec_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xCE, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dec_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x55, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    send_command(fc2, Command::inst(0xDE, &[0x00, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x55, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dec_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x55, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    send_command(fc2, Command::inst(0xDE, &[0x00, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x55, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dey_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x32])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dey_impl_with_rising_z_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x01])?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_dey_impl_with_rising_n_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dey_impl_with_falling_n_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x88, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x32])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_with_rising_z_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x01])?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_with_rising_n_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dex_impl_with_falling_n_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xCA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dec_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC6, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x00, 0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dec_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xCE, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x80])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x06, 0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_dec_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    send_command(fc2, Command::inst(0xDE, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x06])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x06, 0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_dec_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    send_command(fc2, Command::inst(0xDE, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x06])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x06, 0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_dey_impl_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_dey_impl_with_rising_z_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dey_impl_with_rising_n_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dey_impl_with_falling_n_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_dex_impl_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_dex_impl_with_rising_z_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dex_impl_with_rising_n_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dex_impl_with_falling_n_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_dec_zp_within_internal_memory);
+    run_test!(runner, fc2, test_dec_abs_within_internal_memory);
+    run_test!(runner, fc2, test_dec_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_dec_absx_within_internal_memory);
+    run_test!(runner, fc2, test_dey_impl_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_dey_impl_with_rising_z_flag_within_mocking_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dey_impl_with_rising_n_flag_within_mocking_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dey_impl_with_falling_n_flag_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_dex_impl_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_dex_impl_with_rising_z_flag_within_mocking_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dex_impl_with_rising_n_flag_within_mocking_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_dex_impl_with_falling_n_flag_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_dec_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_dec_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_dec_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_dec_absx_within_mocking_memory);
+}
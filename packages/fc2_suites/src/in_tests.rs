@@ -0,0 +1,454 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_inc_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xee, &[0x00, 0x04])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x00, 0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+
+    Ok(())
+}
+
+pub fn test_inc_absx_without_carry_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x60])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x60])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x60, 0x40])?)?;
+    send_command(fc2, Command::inst(0xa2, &[0x30])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xFE, &[0x30, 0x40])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x60, 0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x61])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inc_absx_with_carry_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x60])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x60])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x2F, 0x41])?)?;
+    send_command(fc2, Command::inst(0xa2, &[0xff])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xFE, &[0x30, 0x40])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x2F, 0x41])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x61])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inc_zp_without_triger_of_p_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7E])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7E])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x2C, 0x00])?)?;
+    send_command(fc2, Command::inst(0xe6, &[0x2C])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x2C, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inc_zp_with_zero_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x2C, 0x00])?)?;
+    send_command(fc2, Command::inst(0xe6, &[0x2c])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command(fc2, Command::inst(0xAD, &[0x2C, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_inc_zp_with_negative_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x2C, 0x00])?)?;
+    send_command(fc2, Command::inst(0xe6, &[0x2c])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x2C, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_inc_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xA0])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xA0])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xB7, 0x00])?)?;
+    send_command(fc2, Command::inst(0xa2, &[0x50])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xf6, &[0x67])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xB7, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xA1])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_inx_impl_without_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA2, &[0x59])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x59])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xE8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x5A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inx_impl_with_zero_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA2, &[0x7F])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xE8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_iny_impl_without_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA0, &[0x23])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x23])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x24])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_iny_impl_with_negative_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA0, &[0x9B])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x9B])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xC8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x9C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_inc_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xee, &[0x00, 0x04])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0x40])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x00, 0x04, 0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inc_absx_without_carry_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa2, &[0x30])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xFE, &[0x30, 0x40])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x60, 0x40])?;
+    usart_write(fc2, &[0x60])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x60, 0x40, 0x61])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inc_absx_with_carry_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa2, &[0xff])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xFE, &[0x30, 0x40])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x2F, 0x41])?;
+    usart_write(fc2, &[0x60])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x2F, 0x41, 0x61])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inc_zp_without_triger_of_p_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xe6, &[0x2c])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x2C, 0x00])?;
+    usart_write(fc2, &[0x7e])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x2C, 0x00, 0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inc_zp_with_zero_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xe6, &[0x2c])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x2C, 0x00])?;
+    usart_write(fc2, &[0xff])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x2C, 0x00, 0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_inc_zp_with_negative_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xe6, &[0x2c])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x2C, 0x00])?;
+    usart_write(fc2, &[0x7f])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x2C, 0x00, 0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_inc_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa2, &[0x50])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xf6, &[0x67])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xB7, 0x00])?;
+    usart_write(fc2, &[0xa0])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xB7, 0x00, 0xA1])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_inx_impl_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA2, &[0x59])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x59])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xE8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x5A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_inx_impl_with_zero_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA2, &[0x7F])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xE8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_iny_impl_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA0, &[0x23])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x23])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x24])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_iny_impl_with_negative_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA0, &[0x9B])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x9B])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xC8, &[])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x9C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_inc_abs_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_inc_absx_without_carry_within_internal_memory
+    );
+    run_test!(runner, fc2, test_inc_absx_with_carry_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_inc_zp_without_triger_of_p_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_inc_zp_with_zero_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_inc_zp_with_negative_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_inc_zpx_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_inx_impl_without_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_inx_impl_with_zero_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_iny_impl_without_flag_within_internal_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_iny_impl_with_negative_within_internal_memory
+    );
+    run_test!(runner, fc2, test_inc_abs_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_inc_absx_without_carry_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_inc_absx_with_carry_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_inc_zp_without_triger_of_p_within_mocking_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_inc_zp_with_zero_flag_within_mocking_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_inc_zp_with_negative_flag_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_inc_zpx_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_inx_impl_without_flag_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_inx_impl_with_zero_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_iny_impl_without_flag_within_mocking_memory
+    );
+    run_test!(
+        runner,
+        fc2,
+        test_iny_impl_with_negative_within_mocking_memory
+    );
+}
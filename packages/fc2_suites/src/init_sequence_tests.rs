@@ -0,0 +1,36 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn jmp_c000<T: Transport>(fc2: &mut T) -> CheckResult<T> {
+    send_command(fc2, Command::inst(0x4C, &[0x00, 0xC0])?)?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x00, 0xC0])?;
+    Ok(())
+}
+
+pub fn test_inst_sequence_should_execute_first_ope<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::NesTest))?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    jmp_c000(fc2)?;
+    send_command(fc2, Command::Sequence(0x01))?;
+    // second status of nestest.log
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_inst_sequence_should_execute_first_ope);
+}
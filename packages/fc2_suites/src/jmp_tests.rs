@@ -0,0 +1,119 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::{inst, run_test};
+
+pub fn test_jsr_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("jmp $C5F5"))?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xc5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, inst!("jsr $C72D"))?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x2D, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("lda $01FD"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xC5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("lda $01FC"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+
+    Ok(())
+}
+
+pub fn test_jmp_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("jmp $C5F5"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xf5, 0xc5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_jmp_ind_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$7E"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7E])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0200"))?;
+    send_command(fc2, inst!("lda #$DB"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xDB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("ldy #$01"))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0201"))?;
+    send_command_with_check(fc2, inst!("jmp ($0200)"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x7e, 0xdb])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_jsr_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("jmp $C5F5"))?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xc5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, inst!("jsr $C72D"))?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0xC5])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFC, 0x01, 0xF7])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x2D, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_jmp_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("jmp $C5F5"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xf5, 0xc5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_jmp_ind_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("jmp ($0200)"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x02])?;
+    usart_write(fc2, &[0x7e])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x01, 0x02])?;
+    usart_write(fc2, &[0xdb])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x7e, 0xdb])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_jsr_abs_within_internal_memory);
+    run_test!(runner, fc2, test_jmp_abs_within_internal_memory);
+    run_test!(runner, fc2, test_jmp_ind_within_internal_memory);
+    run_test!(runner, fc2, test_jsr_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_jmp_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_jmp_ind_within_mocking_memory);
+}
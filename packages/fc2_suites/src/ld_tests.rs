@@ -0,0 +1,656 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::{inst, run_test};
+
+pub fn test_lda_nestest_head<D: Transport + ControlPins>(fc2: &mut D) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::NesTest))?;
+    send_command(fc2, inst!("lda $C000"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x4C])?;
+    send_command(fc2, inst!("lda $C001"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF5])?;
+    send_command(fc2, inst!("lda $C002"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xC5])?;
+    Ok(())
+}
+
+pub fn test_lda_indx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$FF"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $0033"))?;
+    send_command(fc2, inst!("lda #$F0"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xF0])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $0034"))?;
+    send_command(fc2, inst!("lda #$CF"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $F0FF"))?;
+    send_command_with_check(fc2, inst!("ldx #$18"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x18])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("lda #$40"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("lda ($1B,X)"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_lda_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$45"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x45])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0025"))?;
+    send_command_with_check(fc2, inst!("lda $25"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x45])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lda_imm_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("lda #$34"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x34])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lda_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("sta $0180"))?;
+    send_command_with_check(fc2, inst!("lda $0180"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_lda_indy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("sta $0089"))?;
+    send_command(fc2, inst!("lda #$03"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x03])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $008A"))?;
+    send_command(fc2, inst!("lda #$89"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x89])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("ldx #$01"))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0300"))?;
+    send_command(fc2, inst!("ldy #$00"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command_with_check(fc2, inst!("lda ($89),Y"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x89])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_lda_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$0C"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x0C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0065"))?;
+    send_command_with_check(fc2, inst!("ldx #$05"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x05])?;
+    send_command(fc2, inst!("lda $60,X"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x0C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lda_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$BB"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xBB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $4174"))?;
+    send_command(fc2, inst!("ldy #$44"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x44])?;
+    send_command_with_check(fc2, inst!("lda $4130,Y"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xBB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_lda_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$D4"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xD4])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $0A1C"))?;
+    send_command(fc2, inst!("ldx #$75"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x75])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, inst!("lda $09A7,X"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xD4])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_imm_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldx #$45"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x45])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$90"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x90])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $00F0"))?;
+    send_command_with_check(fc2, inst!("ldx $F0"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x90])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$FB"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $07FF"))?;
+    send_command(fc2, inst!("ldx $07FF"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_zpy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$3B"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x3B])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0051"))?;
+    send_command(fc2, inst!("ldy #$C0"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xC0])?;
+    send_command_with_check(fc2, inst!("ldx $91,Y"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x3B])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$33"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0678"))?;
+
+    send_command(fc2, inst!("ldy #$78"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("ldx $0600,Y"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_imm_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldy #$BA"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xba])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$DD"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xDD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $00C3"))?;
+    send_command_with_check(fc2, inst!("ldy $C3"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xDD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$55"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("sta $0678"))?;
+    send_command(fc2, inst!("ldy $0678"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("lda #$AA"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("sta $0033"))?;
+    send_command(fc2, inst!("ldy #$01"))?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("ldx #$00"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command_with_check(fc2, inst!("ldy $33,X"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("sta $0689"))?;
+    send_command(fc2, inst!("ldx #$8A"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x8A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, inst!("ldy $05FF,X"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_lda_indx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldx #$18"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x18])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("lda #$40"))?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, inst!("lda ($1B,X)"))?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x33, 0x00])?;
+    usart_write(fc2, &[0xFF])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x34, 0x00])?;
+    usart_write(fc2, &[0xF0])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFF, 0xF0])?;
+    usart_write(fc2, &[0xCF])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_lda_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("lda $25"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x25, 0x00])?;
+    usart_write(fc2, &[0x45])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x45])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lda_imm_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("lda #$34"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x34])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lda_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("lda $0180"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x80, 0x01])?;
+    usart_write(fc2, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_lda_indy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("ldy #$00"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command_with_check(fc2, inst!("lda ($89),Y"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x89, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x8A, 0x00])?;
+    usart_write(fc2, &[0x03])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x03])?;
+    usart_write(fc2, &[0x89])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x89])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_lda_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldx #$05"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x05])?;
+    send_command(fc2, inst!("lda $60,X"))?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x65, 0x00])?;
+    usart_write(fc2, &[0x0C])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x0C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lda_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("ldy #$44"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x44])?;
+    send_command_with_check(fc2, inst!("lda $4130,Y"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x74, 0x41])?;
+    usart_write(fc2, &[0xBB])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xBB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_lda_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("ldx #$75"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x75])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, inst!("lda $09A7,X"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x1C, 0x0A])?;
+    usart_write(fc2, &[0xD4])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xD4])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_imm_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldx #$45"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x45])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldx $F0"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xF0, 0x00])?;
+    usart_write(fc2, &[0x90])?;
+    check_valid_register_status(fc2, TxReg::X, &[0x90])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldx $07FF"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFF, 0x07])?;
+    usart_write(fc2, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::X, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_zpy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("ldy #$C0"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xC0])?;
+    send_command_with_check(fc2, inst!("ldx $91,Y"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x51, 0x00])?;
+    usart_write(fc2, &[0x3B])?;
+    check_valid_register_status(fc2, TxReg::X, &[0x3B])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldx_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("ldy #$78"))?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, inst!("ldx $0600,Y"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::X, &[0x33])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_imm_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldy #$BA"), check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xba])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldy $C3"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xC3, 0x00])?;
+    usart_write(fc2, &[0xDD])?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xDD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, inst!("ldy $0678"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("ldx #$00"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    send_command_with_check(fc2, inst!("ldy $33,X"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x33, 0x00])?;
+    usart_write(fc2, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::Y, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_ldy_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, inst!("ldx #$8A"))?;
+    check_valid_register_status(fc2, TxReg::X, &[0x8A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, inst!("ldy $05FF,X"), check_rw_is_high)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x89, 0x06])?;
+    usart_write(fc2, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_lda_nestest_head);
+    run_test!(runner, fc2, test_lda_indx_within_internal_memory);
+    run_test!(runner, fc2, test_lda_zp_within_internal_memory);
+    run_test!(runner, fc2, test_lda_imm_within_internal_memory);
+    run_test!(runner, fc2, test_lda_abs_within_internal_memory);
+    run_test!(runner, fc2, test_lda_indy_within_internal_memory);
+    run_test!(runner, fc2, test_lda_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_lda_absy_within_internal_memory);
+    run_test!(runner, fc2, test_lda_absx_within_internal_memory);
+    run_test!(runner, fc2, test_ldx_imm_within_internal_memory);
+    run_test!(runner, fc2, test_ldx_zp_within_internal_memory);
+    run_test!(runner, fc2, test_ldx_abs_within_internal_memory);
+    run_test!(runner, fc2, test_ldx_zpy_within_internal_memory);
+    run_test!(runner, fc2, test_ldx_absy_within_internal_memory);
+    run_test!(runner, fc2, test_ldy_imm_within_internal_memory);
+    run_test!(runner, fc2, test_ldy_zp_within_internal_memory);
+    run_test!(runner, fc2, test_ldy_abs_within_internal_memory);
+    run_test!(runner, fc2, test_ldy_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_ldy_absx_within_internal_memory);
+    run_test!(runner, fc2, test_lda_indx_within_mocking_memory);
+    run_test!(runner, fc2, test_lda_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_lda_imm_within_mocking_memory);
+    run_test!(runner, fc2, test_lda_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_lda_indy_within_mocking_memory);
+    run_test!(runner, fc2, test_lda_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_lda_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_lda_absx_within_mocking_memory);
+    run_test!(runner, fc2, test_ldx_imm_within_mocking_memory);
+    run_test!(runner, fc2, test_ldx_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_ldx_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_ldx_zpy_within_mocking_memory);
+    run_test!(runner, fc2, test_ldx_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_ldy_imm_within_mocking_memory);
+    run_test!(runner, fc2, test_ldy_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_ldy_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_ldy_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_ldy_absx_within_mocking_memory);
+}
@@ -0,0 +1,65 @@
+//! Test suites of the FPGA cpu, written against `Transport` and `ControlPins` so that they
+//! run on the `fc2_tests` firmware as well as on the host against `debug_simulator`.
+
+#![no_std]
+
+pub mod adc_tests;
+pub mod bit_tests;
+pub mod bitwise_tests;
+pub mod branch_tests;
+pub mod break_tests;
+pub mod cl_tests;
+pub mod cmp_tests;
+pub mod cp_tests;
+pub mod de_tests;
+pub mod in_tests;
+pub mod init_sequence_tests;
+pub mod jmp_tests;
+pub mod ld_tests;
+pub mod nop_tests;
+pub mod ph_tests;
+pub mod pl_tests;
+pub mod return_tests;
+pub mod sbc_tests;
+pub mod se_tests;
+pub mod sequence_tests;
+pub mod shift_tests;
+pub mod st_tests;
+pub mod t_tests;
+
+use embassy_fc2_app::middleware::runner::Suite;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+
+/// Every suite, run in this order.
+///
+/// `fc2_tests` runs the subset named by `FC2_SUITES`, which `option_env!` reads when the
+/// firmware is built. USART1 is taken by the FPGA and `defmt-rtt` has no down channel for
+/// `probe-run` to write to, so the board has nothing to read a selection from at run time,
+/// and another subset means another build and flash, which test-executor does for each run.
+pub fn registry<D: Transport + ControlPins>() -> [Suite<D>; 23] {
+    [
+        Suite::new("adc", adc_tests::run),
+        Suite::new("bit", bit_tests::run),
+        Suite::new("bitwise", bitwise_tests::run),
+        Suite::new("branch", branch_tests::run),
+        Suite::new("break", break_tests::run),
+        Suite::new("cl", cl_tests::run),
+        Suite::new("cmp", cmp_tests::run),
+        Suite::new("cp", cp_tests::run),
+        Suite::new("de", de_tests::run),
+        Suite::new("in", in_tests::run),
+        Suite::new("init_sequence", init_sequence_tests::run),
+        Suite::new("jmp", jmp_tests::run),
+        Suite::new("ld", ld_tests::run),
+        Suite::new("nop", nop_tests::run),
+        Suite::new("ph", ph_tests::run),
+        Suite::new("pl", pl_tests::run),
+        Suite::new("return", return_tests::run),
+        Suite::new("sbc", sbc_tests::run),
+        Suite::new("se", se_tests::run),
+        Suite::new("sequence", sequence_tests::run),
+        Suite::new("shift", shift_tests::run),
+        Suite::new("st", st_tests::run),
+        Suite::new("t", t_tests::run),
+    ]
+}
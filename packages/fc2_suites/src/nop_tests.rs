@@ -0,0 +1,46 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_nop_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xF5, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xEA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF6, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_nop_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xF5, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF5, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xEA, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xF6, 0xC5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_nop_impl_within_internal_memory);
+    run_test!(runner, fc2, test_nop_impl_within_mocking_memory);
+}
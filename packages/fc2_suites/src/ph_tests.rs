@@ -0,0 +1,180 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_php_impl_within_n_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x08, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0b10100100])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_php_impl_within_cz_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    send_command(fc2, Command::inst(0xCD, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x08, &[])?)?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0b00100111])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_php_impl_within_none_flag_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x08, &[])?)?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0b00100100])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_pha_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x82])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x82])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x82])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x82])?;
+    send_command(fc2, Command::inst(0xCD, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x82])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFC, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x82])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    Ok(())
+}
+
+pub fn test_php_impl_within_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x08, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0b10100100])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    Ok(())
+}
+
+pub fn test_php_impl_within_cz_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    send_command(fc2, Command::inst(0xCD, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x08, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0b00100111])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    Ok(())
+}
+
+pub fn test_php_impl_within_none_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x08, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0b00100100])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    Ok(())
+}
+
+pub fn test_pha_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x82])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x82])?;
+    send_command(fc2, Command::inst(0xCD, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x82])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0x82])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFC, 0x01, 0x82])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_php_impl_within_n_within_internal_memory);
+    run_test!(runner, fc2, test_php_impl_within_cz_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_php_impl_within_none_flag_within_internal_memory
+    );
+    run_test!(runner, fc2, test_pha_impl_within_internal_memory);
+    run_test!(runner, fc2, test_php_impl_within_n_within_mocking_memory);
+    run_test!(runner, fc2, test_php_impl_within_cz_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_php_impl_within_none_flag_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_pha_impl_within_mocking_memory);
+}
@@ -0,0 +1,180 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_rti_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xCE])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0xAD])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAD])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAD])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFC, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x65])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x65])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x65])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFB, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x65])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x65])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x65])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFC, 0x01])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xAD])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFC, 0x01])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xCE])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFD, 0x01])?)?;
+    send_command(fc2, Command::inst(0x40, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAD, 0xCE])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b01100101])?;
+    Ok(())
+}
+
+pub fn test_rts_impl_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xFC, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xFC, 0xc5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x20, &[0x2D, 0xC7])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x2D, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFD, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xC5])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFC, 0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFE])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0xFC, 0x01])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xCE])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x60, &[])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x00, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_rti_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xCE])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0xCE])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0xAD])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAD])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFC])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFC, 0x01, 0xAD])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAD])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x24])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x24])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x48, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFB, 0x01, 0x24])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x24])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x40, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFB, 0x01])?;
+    usart_write(fc2, &[0x24])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFC, 0x01])?;
+    usart_write(fc2, &[0xAD])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFD, 0x01])?;
+    usart_write(fc2, &[0xCE])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xAD, 0xCE])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_rts_impl_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4C, &[0xFC, 0xC5])?)?;
+    check_valid_register_status(fc2, TxReg::PC, &[0xFC, 0xc5])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    send_command(fc2, Command::inst(0x20, &[0x2D, 0xC7])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFD, 0x01, 0xC5])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFC, 0x01, 0xFE])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x2D, 0xC7])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x60, &[])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFC, 0x01])?;
+    usart_write(fc2, &[0xFF])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0xFD, 0x01])?;
+    usart_write(fc2, &[0xC5])?;
+    check_valid_register_status(fc2, TxReg::PC, &[0x00, 0xC6])?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_rti_impl_within_internal_memory);
+    run_test!(runner, fc2, test_rts_impl_within_internal_memory);
+    run_test!(runner, fc2, test_rti_impl_within_mocking_memory);
+    run_test!(runner, fc2, test_rts_impl_within_mocking_memory);
+}
@@ -0,0 +1,475 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_sbc_indx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x80, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x81, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x41])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x02])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE1, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sbc_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x3F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x3F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE5, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_imm_without_carry_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xE9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b01100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_imm_with_carry_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_sbc_imm_with_overflow_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE9, &[0x41])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sbc_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x3F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x3F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xED, &[0x78, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_indy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x8D, &[0x33, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x04])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x34, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xF1, &[0x33])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_sbc_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x41])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xF5, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sbc_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x7F])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x00, 0x04])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA2, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x81])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x81])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    send_command(fc2, Command::inst(0xF9, &[0x00, 0x04])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b01100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x78, 0x06])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0x00])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xFD, &[0x00, 0x06])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_sbc_indx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE1, &[0x80])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x80, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x81, 0x00])?;
+    usart_write(fc2, &[0x02])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x02])?;
+    usart_write(fc2, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sbc_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE5, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x3F])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_imm_without_carry_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xE9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b01100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_imm_with_carry_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_sbc_imm_with_overflow_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xE9, &[0x41])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sbc_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xED, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x3F])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_indy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xF1, &[0x33])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x33, 0x00])?;
+    usart_write(fc2, &[0x00])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x34, 0x00])?;
+    usart_write(fc2, &[0x04])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_sbc_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xF5, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x41])?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sbc_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x81])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x81])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    send_command(fc2, Command::inst(0xF9, &[0x00, 0x04])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x00, 0x04])?;
+    usart_write(fc2, &[0x7F])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b01100101])?;
+    Ok(())
+}
+
+pub fn test_sbc_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA2, &[0x78])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x78])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0xFD, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_sbc_indx_within_internal_memory);
+    run_test!(runner, fc2, test_sbc_zp_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_sbc_imm_without_carry_within_internal_memory
+    );
+    run_test!(runner, fc2, test_sbc_imm_with_carry_within_internal_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_sbc_imm_with_overflow_within_internal_memory
+    );
+    run_test!(runner, fc2, test_sbc_abs_within_internal_memory);
+    run_test!(runner, fc2, test_sbc_indy_within_internal_memory);
+    run_test!(runner, fc2, test_sbc_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_sbc_absy_within_internal_memory);
+    run_test!(runner, fc2, test_sbc_absx_within_internal_memory);
+    run_test!(runner, fc2, test_sbc_indx_within_mocking_memory);
+    run_test!(runner, fc2, test_sbc_zp_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_sbc_imm_without_carry_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_sbc_imm_with_carry_within_mocking_memory);
+    run_test!(
+        runner,
+        fc2,
+        test_sbc_imm_with_overflow_within_mocking_memory
+    );
+    run_test!(runner, fc2, test_sbc_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_sbc_indy_within_mocking_memory);
+    run_test!(runner, fc2, test_sbc_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_sbc_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_sbc_absx_within_mocking_memory);
+}
@@ -0,0 +1,35 @@
+use embassy_fc2_app::middleware::check::{CheckError, CheckKind, CheckResult};
+use embassy_fc2_app::middleware::checkpoint::{run_table, CheckpointTable};
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::{info, run_test};
+
+use crate::init_sequence_tests::jmp_c000;
+
+// written by dump_sequence, see build.rs.
+static CHECKPOINTS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/checkpoints.bin"));
+
+/// Resets the FPGA and leaves the cpu on line 1 of nestest.log.
+pub fn start_nestest<D: Transport + ControlPins>(fc2: &mut D) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::NesTest))?;
+    check_valid_register_status(fc2, TxReg::S, &[0xFD])?;
+    jmp_c000(fc2)
+}
+
+pub fn test_inst_sequence<D: Transport + ControlPins>(fc2: &mut D) -> CheckResult<D> {
+    let table = CheckpointTable::parse(CHECKPOINTS)
+        .map_err(|error| CheckError::new(CheckKind::Table(error), &[], &[]))?;
+    info!("{} checkpoints, {}.", table.len(), table.mode());
+    run_table(fc2, &table, start_nestest)?;
+
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_inst_sequence);
+}
@@ -0,0 +1,474 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_asl_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x06, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x80])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x00, 0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_asl_acc_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x21])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x21])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x0A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x42])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_asl_acc_with_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x55])?;
+    send_command(fc2, Command::inst(0x0A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_asl_acc_with_cz_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x0A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_asl_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x0E, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x80])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x06, 0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_asl_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x16, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x00])?;
+    usart_write(fc2, &[0x55])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x00, 0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_asl_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x1E, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x06])?;
+    usart_write(fc2, &[0x55])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x06, 0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_rol_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x26, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x55])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x00, 0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_rol_acc_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x20])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x20])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x2A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_rol_acc_with_c_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x80])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    send_command(fc2, Command::inst(0x2A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_rol_acc_with_z_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x2A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100110])?;
+    Ok(())
+}
+
+pub fn test_rol_acc_with_n_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x40])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x40])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x2A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_rol_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x2E, &[0x78, 0x26])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x26])?;
+    usart_write(fc2, &[0x55])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x26, 0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_rol_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0x36, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x00])?;
+    usart_write(fc2, &[0x80])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x00, 0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_rol_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0x3E, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x06])?;
+    usart_write(fc2, &[0x80])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x06, 0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_lsr_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x46, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x01])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x00, 0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_lsr_acc_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x0C])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x0C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x4A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x06])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lsr_acc_with_cz_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x4A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_lsr_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x4E, &[0x78, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x06])?;
+    usart_write(fc2, &[0x01])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x06, 0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_lsr_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x56, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x00])?;
+    usart_write(fc2, &[0xAA])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x00, 0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_lsr_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x5E, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x06])?;
+    usart_write(fc2, &[0xAA])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x06, 0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ror_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0x66, &[0x78])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x00])?;
+    usart_write(fc2, &[0x01])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x00, 0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    Ok(())
+}
+
+pub fn test_ror_acc_without_flag_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x02])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x02])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x6A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_ror_acc_with_zc_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x6A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x00])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    Ok(())
+}
+
+pub fn test_ror_acc_with_cn_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA9, &[0x01])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x01])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0x6A, &[])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    Ok(())
+}
+
+pub fn test_ror_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0x6E, &[0x78, 0x26])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x78, 0x26])?;
+    usart_write(fc2, &[0x55])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x78, 0x26, 0x2A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_ror_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x76, &[0x00])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x00])?;
+    usart_write(fc2, &[0x55])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x00, 0x2A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    Ok(())
+}
+
+pub fn test_ror_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xC9, &[0x00])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100111])?;
+    send_command(fc2, Command::inst(0xA2, &[0x55])?)?;
+    check_valid_register_status(fc2, TxReg::X, &[0x55])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100101])?;
+    send_command(fc2, Command::inst(0x7E, &[0x00, 0x06])?)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x55, 0x06])?;
+    usart_write(fc2, &[0x01])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x55, 0x06, 0x80])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100101])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_asl_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_asl_acc_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_asl_acc_with_n_within_mocking_memory);
+    run_test!(runner, fc2, test_asl_acc_with_cz_within_mocking_memory);
+    run_test!(runner, fc2, test_asl_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_asl_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_asl_absx_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_acc_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_acc_with_c_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_acc_with_z_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_acc_with_n_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_rol_absx_within_mocking_memory);
+    run_test!(runner, fc2, test_lsr_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_lsr_acc_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_lsr_acc_with_cz_within_mocking_memory);
+    run_test!(runner, fc2, test_lsr_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_lsr_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_lsr_absx_within_mocking_memory);
+    run_test!(runner, fc2, test_ror_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_ror_acc_without_flag_within_mocking_memory);
+    run_test!(runner, fc2, test_ror_acc_with_zc_within_mocking_memory);
+    run_test!(runner, fc2, test_ror_acc_with_cn_within_mocking_memory);
+    run_test!(runner, fc2, test_ror_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_ror_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_ror_absx_within_mocking_memory);
+}
@@ -0,0 +1,482 @@
+use embassy_fc2_app::middleware::check::CheckResult;
+use embassy_fc2_app::middleware::mode::command::Command;
+use embassy_fc2_app::middleware::mode::*;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_fc2_app::middleware::util::*;
+use embassy_fc2_app::run_test;
+
+pub fn test_sta_indx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x59])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x59])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x09, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xBA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xBA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x0A, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x1])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0xA2, &[0xC4])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0xC4])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x03])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x03])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x81, &[0x45])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x59, 0xBA])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x03])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa9, &[0xbb])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x85, &[0x45])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x45, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xBB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sta_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x2A])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x8D, &[0x11, 0x33])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x11, 0x33])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x2A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_indy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x97, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0x8D, &[0x98, 0x00])?)?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA0, &[0x34])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x34])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x91, &[0x97])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x33, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sta_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa9, &[0xbb])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xa2, &[0x11])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x95, &[0x45])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x56, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xBB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sta_absy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0xF0])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x2A])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x99, &[0x23, 0x34])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x13, 0x35])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x2A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_absx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x7B])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0xA7])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x9D, &[0x80, 0x20])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xFB, 0x20])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xA7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_stx_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa2, &[0xaa])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x86, &[0x45])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x45, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_stx_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0xA0])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x8E, &[0xBB, 0x03])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0xBB, 0x03])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0xA0])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_stx_zpy_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa2, &[0x6c])?)?;
+    send_command(fc2, Command::inst(0xa0, &[0x34])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x96, &[0x22])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x56, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x6C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sty_zp_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa0, &[0x2c])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x84, &[0x45])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x45, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x2C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sty_abs_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x44])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x8C, &[0x00, 0xA9])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x00, 0xA9])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x44])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sty_zpx_within_internal_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinInternalMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0xCC])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x90])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x94, &[0x45])?, check_rw_is_low)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xAD, &[0x11, 0x00])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x90])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sta_indx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command_with_check(fc2, Command::inst(0xA2, &[0xC4])?, check_rw_is_high)?;
+    check_valid_register_status(fc2, TxReg::X, &[0xC4])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x03])?)?;
+    check_valid_register_status(fc2, TxReg::A, &[0x03])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x81, &[0x45])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x09, 0x00])?;
+    usart_write(fc2, &[0x59])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x0A, 0x00])?;
+    usart_write(fc2, &[0xBA])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x59, 0xBA, 0x03])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa9, &[0xbb])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x85, &[0x45])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x45, 0x00, 0xBB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_sta_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0x2A])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x8D, &[0x11, 0x33])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x11, 0x33, 0x2A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_indy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA9, &[0xFF])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA0, &[0x34])?)?;
+    check_valid_register_status(fc2, TxReg::Y, &[0x34])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x91, &[0x97])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x97, 0x00])?;
+    usart_write(fc2, &[0xFF])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 2], &[0x98, 0x00])?;
+    usart_write(fc2, &[0xFF])?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x33, 0x00, 0xFF])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa9, &[0xbb])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xa2, &[0x11])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x95, &[0x45])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x56, 0x00, 0xBB])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_absy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0xF0])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0x2A])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x99, &[0x23, 0x34])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x13, 0x35, 0x2A])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sta_absx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0x7B])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command(fc2, Command::inst(0xA9, &[0xA7])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x9D, &[0x80, 0x20])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xFB, 0x20, 0xA7])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_stx_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa2, &[0xaa])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x86, &[0x45])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x45, 0x00, 0xAA])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_stx_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0xA0])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x8E, &[0xBB, 0x03])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0xBB, 0x03, 0xA0])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn test_stx_zpy_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa2, &[0x6c])?)?;
+    send_command(fc2, Command::inst(0xa0, &[0x34])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x96, &[0x22])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x56, 0x00, 0x6C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sty_zp_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xa0, &[0x2c])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x84, &[0x45])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x45, 0x00, 0x2C])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sty_abs_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA0, &[0x44])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    send_command_with_check(fc2, Command::inst(0x8C, &[0x00, 0xA9])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x00, 0xA9, 0x44])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b00100100])?;
+    Ok(())
+}
+
+pub fn test_sty_zpx_within_mocking_memory<D: Transport + ControlPins>(
+    fc2: &mut D,
+) -> CheckResult<D> {
+    send_reset_signal_if_not_nop(fc2)?;
+    send_command(fc2, Command::CpuMode(CpuMode::DebugWithinMockMemory))?;
+    send_command(fc2, Command::CassetteMode(CassetteMode::None))?;
+    send_command(fc2, Command::inst(0xA2, &[0xCC])?)?;
+    send_command(fc2, Command::inst(0xA0, &[0x90])?)?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    send_command_with_check(fc2, Command::inst(0x94, &[0x45])?, check_rw_is_low)?;
+    usart_read_with_check(fc2, &mut [0x0u8; 3], &[0x11, 0x00, 0x90])?;
+    check_valid_register_status(fc2, TxReg::P, &[0b10100100])?;
+    Ok(())
+}
+
+pub fn run<D: Transport + ControlPins>(runner: &mut TestRunner, fc2: &mut D) {
+    run_test!(runner, fc2, test_sta_indx_within_internal_memory);
+    run_test!(runner, fc2, test_sta_zp_within_internal_memory);
+    run_test!(runner, fc2, test_sta_abs_within_internal_memory);
+    run_test!(runner, fc2, test_sta_indy_within_internal_memory);
+    run_test!(runner, fc2, test_sta_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_sta_absy_within_internal_memory);
+    run_test!(runner, fc2, test_sta_absx_within_internal_memory);
+    run_test!(runner, fc2, test_stx_zp_within_internal_memory);
+    run_test!(runner, fc2, test_stx_abs_within_internal_memory);
+    run_test!(runner, fc2, test_stx_zpy_within_internal_memory);
+    run_test!(runner, fc2, test_sty_zp_within_internal_memory);
+    run_test!(runner, fc2, test_sty_abs_within_internal_memory);
+    run_test!(runner, fc2, test_sty_zpx_within_internal_memory);
+    run_test!(runner, fc2, test_sta_indx_within_mocking_memory);
+    run_test!(runner, fc2, test_sta_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_sta_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_sta_indy_within_mocking_memory);
+    run_test!(runner, fc2, test_sta_zpx_within_mocking_memory);
+    run_test!(runner, fc2, test_sta_absy_within_mocking_memory);
+    run_test!(runner, fc2, test_sta_absx_within_mocking_memory);
+    run_test!(runner, fc2, test_stx_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_stx_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_stx_zpy_within_mocking_memory);
+    run_test!(runner, fc2, test_sty_zp_within_mocking_memory);
+    run_test!(runner, fc2, test_sty_abs_within_mocking_memory);
+    run_test!(runner, fc2, test_sty_zpx_within_mocking_memory);
+}
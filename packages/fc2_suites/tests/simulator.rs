@@ -0,0 +1,76 @@
+use debug_simulator::DebugSimulator;
+use dump_sequence::table::checkpoint_table;
+use dump_sequence::trace::{self, NESTEST_LOG};
+use embassy_fc2_app::middleware::check::CheckKind;
+use embassy_fc2_app::middleware::checkpoint::{bisect_checkpoints, CheckpointTable, TableMode};
+use embassy_fc2_app::middleware::mode::TxReg;
+use embassy_fc2_app::middleware::runner::TestRunner;
+use fc2_suites::sequence_tests::start_nestest;
+
+const NESTEST_MIF: &str = "../../hex_init_files/nestest.mif";
+
+/// Suites that the simulator does not pass. These tests expect BRK to push the address
+/// right after its opcode, where the reference cpu skips the padding byte as a 6502 does.
+const DIVERGENT: &[&str] = &["break"];
+
+fn simulator() -> DebugSimulator {
+    let source = std::fs::read_to_string(NESTEST_MIF).unwrap();
+    let prg = mif::Mif::parse(&source).unwrap().to_bytes().unwrap();
+    let mut fc2 = DebugSimulator::new();
+    fc2.memory_mut().load_nestest(&prg);
+    fc2
+}
+
+#[test]
+fn suites_pass_against_simulator() {
+    let mut fc2 = simulator();
+    let registry = fc2_suites::registry();
+    let names: Vec<&str> = registry
+        .iter()
+        .map(|suite| suite.name)
+        .filter(|name| !DIVERGENT.contains(name))
+        .collect();
+    let mut runner = TestRunner::new();
+    runner.run_suites(&mut fc2, &registry, Some(&names.join(",")));
+    assert_eq!(runner.failed(), 0);
+    assert!(runner.passed() > 0);
+}
+
+/// Bisect table of nestest.log lines 2 to `last`, one instruction apart. From line
+/// `diverge_from` on, every record expects S to be one lower than the log has it.
+fn bisect_table(last: usize, diverge_from: usize) -> Vec<u8> {
+    let mut lines = trace::load(NESTEST_LOG).unwrap();
+    for line in lines
+        .iter_mut()
+        .filter(|line| line.line_number >= diverge_from)
+    {
+        line.registers.s = line.registers.s.wrapping_sub(1);
+    }
+    let checkpoints: Vec<_> = lines[1..last].iter().collect();
+    checkpoint_table(&checkpoints, TableMode::Bisect).unwrap()
+}
+
+#[test]
+fn bisect_finds_the_first_divergent_line() {
+    let bytes = bisect_table(400, 237);
+    let table = CheckpointTable::parse(&bytes).unwrap();
+    let error = bisect_checkpoints(&mut simulator(), &table, start_nestest).unwrap_err();
+    let CheckKind::State(diff) = error.kind else {
+        panic!("not a state mismatch: {:?}", error);
+    };
+    assert_eq!(diff.registers().collect::<Vec<_>>(), [TxReg::S]);
+    // the mismatch carries the registers of the record it stopped at.
+    let line = table
+        .iter()
+        .find(|checkpoint| checkpoint.registers == diff.expected)
+        .map(|checkpoint| checkpoint.line);
+    assert_eq!(line, Some(237));
+}
+
+#[test]
+fn bisect_passes_when_nothing_diverges() {
+    let bytes = bisect_table(400, usize::MAX);
+    let table = CheckpointTable::parse(&bytes).unwrap();
+    assert_eq!(table.len(), 399);
+    bisect_checkpoints(&mut simulator(), &table, start_nestest).unwrap();
+}
@@ -0,0 +1,252 @@
+//! iNES and NES 2.0 cartridges, split into the images of the FPGA block rams.
+//!
+//! The FPGA has a fixed 16KiB ram for each of $8000-$BFFF and $C000-$FFFF and a 4KiB ram
+//! for each ppu pattern table, so only NROM cartridges fit.
+
+use crate::{Ip, Mif};
+use std::fmt;
+
+pub const HEADER_LEN: usize = 16;
+pub const MAGIC: [u8; 4] = *b"NES\x1a";
+const TRAINER_LEN: usize = 512;
+/// Unit of the PRG-ROM size in the header, and the size of `ip_prg1_rom` and `ip_prg2_rom`.
+pub const PRG_BANK_LEN: usize = 0x4000;
+/// Unit of the CHR-ROM size in the header, both pattern tables.
+pub const CHR_BANK_LEN: usize = 0x2000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    INes,
+    Nes2,
+}
+
+/// Nametable layout the cartridge wires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mirroring {
+    Horizontal,
+    Vertical,
+    /// The cartridge brings its own nametable ram.
+    FourScreen,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub format: Format,
+    pub mapper: u16,
+    /// Always 0 for iNES.
+    pub submapper: u8,
+    pub prg_rom_len: usize,
+    /// 0 when the cartridge has CHR-RAM instead.
+    pub chr_rom_len: usize,
+    pub mirroring: Mirroring,
+    /// Battery backed PRG-RAM at $6000-$7FFF.
+    pub battery: bool,
+    /// 512 bytes for $7000-$71FF between the header and PRG-ROM.
+    pub trainer: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    Magic,
+    /// The file ends before the sizes in the header say it does.
+    Truncated {
+        expected: usize,
+        actual: usize,
+    },
+    UnsupportedMapper(u16),
+    /// PRG-ROM that is not 16KiB or 32KiB.
+    PrgRomLen(usize),
+    /// CHR-ROM that is neither absent nor 8KiB.
+    ChrRomLen(usize),
+    /// The header gives ROM sizes that add up to more than a file can hold.
+    RomSize,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Magic => write!(f, "not an iNES file, it does not start with `NES\\x1a`"),
+            Error::Truncated { expected, actual } => write!(
+                f,
+                "file is {} bytes but its header needs {}",
+                actual, expected
+            ),
+            Error::UnsupportedMapper(mapper) => write!(
+                f,
+                "mapper {} ({}) is not supported, the FPGA only has the fixed banks of mapper 0 (NROM)",
+                mapper,
+                mapper_name(*mapper)
+            ),
+            Error::PrgRomLen(len) => write!(
+                f,
+                "PRG-ROM is {} bytes, NROM has 16KiB or 32KiB",
+                len
+            ),
+            Error::ChrRomLen(len) => write!(f, "CHR-ROM is {} bytes, NROM has 8KiB", len),
+            Error::RomSize => write!(f, "ROM sizes in the header do not fit in memory"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Usual name of the common mappers, for messages.
+pub fn mapper_name(mapper: u16) -> &'static str {
+    match mapper {
+        0 => "NROM",
+        1 => "MMC1",
+        2 => "UxROM",
+        3 => "CNROM",
+        4 => "MMC3",
+        5 => "MMC5",
+        7 => "AxROM",
+        9 => "MMC2",
+        10 => "MMC4",
+        66 => "GxROM",
+        _ => "unknown",
+    }
+}
+
+impl Header {
+    pub fn parse(bytes: &[u8; HEADER_LEN]) -> Result<Self, Error> {
+        if bytes[..4] != MAGIC {
+            return Err(Error::Magic);
+        }
+        let flags6 = bytes[6];
+        let format = match bytes[7] & 0x0C {
+            0x08 => Format::Nes2,
+            _ => Format::INes,
+        };
+        let mut mapper = (flags6 >> 4) as u16;
+        let (mut submapper, mut prg_rom_len, mut chr_rom_len) = (
+            0,
+            bytes[4] as usize * PRG_BANK_LEN,
+            bytes[5] as usize * CHR_BANK_LEN,
+        );
+        match format {
+            Format::Nes2 => {
+                mapper |= (bytes[7] & 0xF0) as u16 | ((bytes[8] & 0x0F) as u16) << 8;
+                submapper = bytes[8] >> 4;
+                prg_rom_len =
+                    rom_len(bytes[4], bytes[9] & 0x0F, PRG_BANK_LEN).ok_or(Error::RomSize)?;
+                chr_rom_len =
+                    rom_len(bytes[5], bytes[9] >> 4, CHR_BANK_LEN).ok_or(Error::RomSize)?;
+            }
+            // old dumpers wrote their name over bytes 7-15, which leaves the upper nibble of
+            // the mapper as garbage.
+            Format::INes if bytes[12..].iter().all(|&byte| byte == 0) => {
+                mapper |= (bytes[7] & 0xF0) as u16;
+            }
+            Format::INes => {}
+        }
+        Ok(Self {
+            format,
+            mapper,
+            submapper,
+            prg_rom_len,
+            chr_rom_len,
+            mirroring: match (flags6 & 0x08 != 0, flags6 & 0x01 != 0) {
+                (true, _) => Mirroring::FourScreen,
+                (false, false) => Mirroring::Horizontal,
+                (false, true) => Mirroring::Vertical,
+            },
+            battery: flags6 & 0x02 != 0,
+            trainer: flags6 & 0x04 != 0,
+        })
+    }
+}
+
+/// ROM size of NES 2.0, a count of `unit` or, when `msb` is $F, `2^E * (M * 2 + 1)` bytes
+/// from `lsb` written as `EEEEEEMM`. `None` if that does not fit in a `usize`.
+fn rom_len(lsb: u8, msb: u8, unit: usize) -> Option<usize> {
+    match msb {
+        0x0F => {
+            let power = 1usize.checked_shl((lsb >> 2) as u32)?;
+            power.checked_mul((lsb & 0x03) as usize * 2 + 1)
+        }
+        _ => ((msb as usize) << 8 | lsb as usize).checked_mul(unit),
+    }
+}
+
+/// Contents of a .nes file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rom {
+    pub header: Header,
+    pub prg_rom: Vec<u8>,
+    pub chr_rom: Vec<u8>,
+}
+
+/// Initial contents of one block ram.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub ip: &'static Ip,
+    pub mif: Mif,
+}
+
+impl Rom {
+    pub fn parse(bytes: &[u8]) -> Result<Self, Error> {
+        let truncated = |expected| Error::Truncated {
+            expected,
+            actual: bytes.len(),
+        };
+        let header = bytes
+            .get(..HEADER_LEN)
+            .ok_or(truncated(HEADER_LEN))?
+            .try_into()
+            .unwrap();
+        let header = Header::parse(header)?;
+        let prg_start = HEADER_LEN + if header.trainer { TRAINER_LEN } else { 0 };
+        let chr_start = prg_start
+            .checked_add(header.prg_rom_len)
+            .ok_or(Error::RomSize)?;
+        let end = chr_start
+            .checked_add(header.chr_rom_len)
+            .ok_or(Error::RomSize)?;
+        if bytes.len() < end {
+            return Err(truncated(end));
+        }
+        Ok(Self {
+            header,
+            prg_rom: bytes[prg_start..chr_start].to_vec(),
+            chr_rom: bytes[chr_start..end].to_vec(),
+        })
+    }
+
+    /// PRG-ROM split into `ip_prg1_rom` and `ip_prg2_rom`, and CHR-ROM into
+    /// `ip_pattern_table_00` and `ip_pattern_table_01`.
+    ///
+    /// 16KiB of PRG-ROM goes into both, as NROM-128 mirrors it at $C000. A cartridge with
+    /// CHR-RAM gets no pattern table images.
+    pub fn images(&self) -> Result<Vec<Image>, Error> {
+        if self.header.mapper != 0 {
+            return Err(Error::UnsupportedMapper(self.header.mapper));
+        }
+        let prg_rom2 = match self.prg_rom.len() {
+            PRG_BANK_LEN => &self.prg_rom[..],
+            len if len == 2 * PRG_BANK_LEN => &self.prg_rom[PRG_BANK_LEN..],
+            len => return Err(Error::PrgRomLen(len)),
+        };
+        let mut images = vec![
+            image("ip_prg1_rom", &self.prg_rom[..PRG_BANK_LEN]),
+            image("ip_prg2_rom", prg_rom2),
+        ];
+        match self.chr_rom.len() {
+            0 => {}
+            CHR_BANK_LEN => {
+                let (table_00, table_01) = self.chr_rom.split_at(CHR_BANK_LEN / 2);
+                images.push(image("ip_pattern_table_00", table_00));
+                images.push(image("ip_pattern_table_01", table_01));
+            }
+            len => return Err(Error::ChrRomLen(len)),
+        }
+        Ok(images)
+    }
+}
+
+fn image(ip: &str, bytes: &[u8]) -> Image {
+    let ip = Ip::find(ip).unwrap();
+    Image {
+        ip,
+        mif: Mif::from_bytes(bytes, ip.depth).unwrap(),
+    }
+}
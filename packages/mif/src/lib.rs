@@ -0,0 +1,559 @@
+//! Quartus Memory Initialization Files, the way a cassette gets into the FPGA block rams.
+//!
+//! ```text
+//! WIDTH=8;
+//! DEPTH=16384;
+//! ADDRESS_RADIX=HEX;
+//! DATA_RADIX=HEX;
+//! CONTENT BEGIN
+//!  0000: 4c f5 c5 60 78 d8 a2 ff 9a ad 02 20 10 fb ad 02;
+//!  [0010..3fff]: 00;
+//! END;
+//! ```
+//!
+//! Comments are `-- ...` up to the end of the line or `% ... %`. Entries are kept in the
+//! order they are written, so a file written back is the same file. A later entry overwrites
+//! an earlier one, and words that no entry covers are zero.
+
+pub mod ines;
+
+use std::fmt;
+use std::ops::Range;
+
+/// Words on each content line [`Mif`] writes, as in `hex_init_files/nestest.mif`.
+pub const WORDS_PER_LINE: usize = 16;
+
+/// `ADDRESS_RADIX` or `DATA_RADIX`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radix {
+    Bin,
+    Oct,
+    /// Signed decimal, so data may be negative.
+    Dec,
+    Hex,
+    /// Unsigned decimal.
+    Uns,
+}
+
+impl Radix {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "BIN" => Some(Radix::Bin),
+            "OCT" => Some(Radix::Oct),
+            "DEC" => Some(Radix::Dec),
+            "HEX" => Some(Radix::Hex),
+            "UNS" => Some(Radix::Uns),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Radix::Bin => "BIN",
+            Radix::Oct => "OCT",
+            Radix::Dec => "DEC",
+            Radix::Hex => "HEX",
+            Radix::Uns => "UNS",
+        }
+    }
+
+    fn base(self) -> u32 {
+        match self {
+            Radix::Bin => 2,
+            Radix::Oct => 8,
+            Radix::Dec | Radix::Uns => 10,
+            Radix::Hex => 16,
+        }
+    }
+
+    /// Digits needed for `value`, which is how wide every address or word is written.
+    fn digits(self, mut value: u64) -> usize {
+        let mut digits = 1;
+        while value >= self.base() as u64 {
+            value /= self.base() as u64;
+            digits += 1;
+        }
+        digits
+    }
+
+    fn format(self, value: u64, digits: usize) -> String {
+        match self {
+            Radix::Bin => format!("{:0digits$b}", value),
+            Radix::Oct => format!("{:0digits$o}", value),
+            Radix::Dec | Radix::Uns => format!("{:0digits$}", value),
+            Radix::Hex => format!("{:0digits$x}", value),
+        }
+    }
+}
+
+/// Parse failure with the line it happened on, counted from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Addresses of a content entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Address {
+    /// `addr: word word ...`, one word after another from `addr`.
+    At(usize),
+    /// `[first..last]: word ...`, the words repeated up to and including `last`.
+    Range(usize, usize),
+}
+
+/// One `address: data;` statement of the content.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub address: Address,
+    pub words: Vec<u64>,
+    /// Line it was read from, 0 for an entry that was not.
+    pub line: usize,
+}
+
+impl Entry {
+    pub fn addresses(&self) -> Range<usize> {
+        match self.address {
+            Address::At(first) => first..first + self.words.len(),
+            Address::Range(first, last) => first..last + 1,
+        }
+    }
+}
+
+/// Addresses an entry writes again after an earlier one did.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overwrite {
+    pub line: usize,
+    pub previous_line: usize,
+    pub addresses: Range<usize>,
+}
+
+/// Contents of a .mif file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mif {
+    /// Bits of each word, up to 64.
+    pub width: u32,
+    pub depth: usize,
+    pub address_radix: Radix,
+    pub data_radix: Radix,
+    pub entries: Vec<Entry>,
+}
+
+impl Mif {
+    /// `depth` words of `width` bits without any entry, written in hex.
+    pub fn new(width: u32, depth: usize) -> Self {
+        Self {
+            width,
+            depth,
+            address_radix: Radix::Hex,
+            data_radix: Radix::Hex,
+            entries: vec![],
+        }
+    }
+
+    /// 8 bit words from `bytes`, padded with zeros up to `depth` and split into lines of
+    /// [`WORDS_PER_LINE`]. `None` if `bytes` is longer than `depth`.
+    pub fn from_bytes(bytes: &[u8], depth: usize) -> Option<Self> {
+        if bytes.len() > depth {
+            return None;
+        }
+        let mut words: Vec<u64> = bytes.iter().map(|&byte| byte as u64).collect();
+        words.resize(depth, 0);
+        let mut mif = Self::new(8, depth);
+        mif.entries = words
+            .chunks(WORDS_PER_LINE)
+            .enumerate()
+            .map(|(i, words)| Entry {
+                address: Address::At(i * WORDS_PER_LINE),
+                words: words.to_vec(),
+                line: 0,
+            })
+            .collect();
+        Some(mif)
+    }
+
+    /// Word at every address after all entries are applied.
+    pub fn words(&self) -> Vec<u64> {
+        let mut words = vec![0; self.depth];
+        for entry in &self.entries {
+            for (address, &word) in entry.addresses().zip(entry.words.iter().cycle()) {
+                words[address] = word;
+            }
+        }
+        words
+    }
+
+    /// Words as bytes, if they are 8 bits wide.
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        (self.width == 8).then(|| self.words().iter().map(|&word| word as u8).collect())
+    }
+
+    /// Addresses that more than one entry writes, where the last one wins.
+    pub fn overwrites(&self) -> Vec<Overwrite> {
+        let mut writer = vec![None; self.depth];
+        let mut overwrites: Vec<Overwrite> = vec![];
+        for entry in &self.entries {
+            for address in entry.addresses() {
+                if let Some(previous_line) = writer[address].replace(entry.line) {
+                    match overwrites.last_mut() {
+                        Some(last)
+                            if last.line == entry.line
+                                && last.previous_line == previous_line
+                                && last.addresses.end == address =>
+                        {
+                            last.addresses.end += 1
+                        }
+                        _ => overwrites.push(Overwrite {
+                            line: entry.line,
+                            previous_line,
+                            addresses: address..address + 1,
+                        }),
+                    }
+                }
+            }
+        }
+        overwrites
+    }
+
+    /// Addresses that no entry writes.
+    pub fn unwritten(&self) -> usize {
+        let mut written = vec![false; self.depth];
+        for entry in &self.entries {
+            for address in entry.addresses() {
+                written[address] = true;
+            }
+        }
+        written.iter().filter(|&&written| !written).count()
+    }
+
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        Parser::default().parse(source)
+    }
+
+    /// Fails unless the file fills `ip` exactly, since Quartus pads or drops the rest
+    /// without telling.
+    pub fn check(&self, ip: &Ip) -> Result<(), String> {
+        if self.width != ip.width {
+            return Err(format!(
+                "WIDTH is {} but {} is {} bits wide",
+                self.width, ip.name, ip.width
+            ));
+        }
+        if self.depth != ip.depth {
+            return Err(format!(
+                "DEPTH is {} but {} has {} words",
+                self.depth, ip.name, ip.depth
+            ));
+        }
+        Ok(())
+    }
+
+    fn max_word(&self) -> u64 {
+        match self.width {
+            64 => u64::MAX,
+            width => (1 << width) - 1,
+        }
+    }
+}
+
+/// Writes the layout of `hex_init_files/nestest.mif`, one entry on each line with addresses
+/// and words padded to the same number of digits.
+impl fmt::Display for Mif {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "WIDTH={};", self.width)?;
+        writeln!(f, "DEPTH={};", self.depth)?;
+        writeln!(f, "ADDRESS_RADIX={};", self.address_radix.as_str())?;
+        writeln!(f, "DATA_RADIX={};", self.data_radix.as_str())?;
+        writeln!(f, "CONTENT BEGIN")?;
+        let address_digits = self
+            .address_radix
+            .digits(self.depth.saturating_sub(1) as u64);
+        let address = |address| self.address_radix.format(address as u64, address_digits);
+        let data_digits = self.data_radix.digits(self.max_word());
+        for entry in &self.entries {
+            match entry.address {
+                Address::At(first) => write!(f, " {}:", address(first))?,
+                Address::Range(first, last) => {
+                    write!(f, " [{}..{}]:", address(first), address(last))?
+                }
+            }
+            for &word in &entry.words {
+                write!(f, " {}", self.data_radix.format(word, data_digits))?;
+            }
+            writeln!(f, ";")?;
+        }
+        writeln!(f, "END;")
+    }
+}
+
+#[derive(Default)]
+struct Parser {
+    width: Option<u32>,
+    depth: Option<usize>,
+    address_radix: Option<Radix>,
+    data_radix: Option<Radix>,
+    /// Set once `CONTENT BEGIN` is read.
+    mif: Option<Mif>,
+    ended: bool,
+}
+
+impl Parser {
+    fn parse(mut self, source: &str) -> Result<Mif, Error> {
+        let source = strip_comments(source);
+        let mut line = 1;
+        let error = |line| move |message| Error { line, message };
+        for statement in source.split(';') {
+            // errors point at where the statement starts, not at its leading blank lines.
+            let mut start = line + leading_newlines(statement);
+            line += statement.matches('\n').count();
+            let mut statement = statement.trim();
+            // `CONTENT BEGIN` has no `;`, so the first entry is in the same statement.
+            if self.mif.is_none() {
+                if let Some(rest) = strip_keywords(statement, &["CONTENT", "BEGIN"]) {
+                    self.begin().map_err(error(start))?;
+                    start += leading_newlines(rest);
+                    statement = rest.trim();
+                }
+            }
+            if statement.is_empty() {
+                continue;
+            }
+            self.statement(statement, start).map_err(error(start))?;
+        }
+        match (self.mif, self.ended) {
+            (Some(mif), true) => Ok(mif),
+            (Some(_), false) => Err(Error {
+                line,
+                message: "missing END".to_string(),
+            }),
+            (None, _) => Err(Error {
+                line,
+                message: "missing CONTENT BEGIN".to_string(),
+            }),
+        }
+    }
+
+    fn statement(&mut self, statement: &str, line: usize) -> Result<(), String> {
+        if self.ended {
+            return Err(format!("`{}` after END", statement));
+        }
+        if self.mif.is_none() {
+            return self.header(statement);
+        }
+        if statement.eq_ignore_ascii_case("END") {
+            self.ended = true;
+            return Ok(());
+        }
+        self.entry(statement, line)
+    }
+
+    fn header(&mut self, statement: &str) -> Result<(), String> {
+        let (key, value) = statement
+            .split_once('=')
+            .ok_or_else(|| format!("expected `KEY=VALUE`, found `{}`", statement))?;
+        let (key, value) = (key.trim(), value.trim());
+        let radix = || Radix::parse(value).ok_or_else(|| format!("unknown radix `{}`", value));
+        match key.to_ascii_uppercase().as_str() {
+            "WIDTH" => match value.parse() {
+                Ok(width @ 1..=64) => self.width = Some(width),
+                _ => return Err(format!("invalid WIDTH `{}`", value)),
+            },
+            "DEPTH" => match value.parse() {
+                Ok(depth) if depth > 0 => self.depth = Some(depth),
+                _ => return Err(format!("invalid DEPTH `{}`", value)),
+            },
+            "ADDRESS_RADIX" => self.address_radix = Some(radix()?),
+            "DATA_RADIX" => self.data_radix = Some(radix()?),
+            _ => return Err(format!("unknown header `{}`", key)),
+        }
+        Ok(())
+    }
+
+    fn begin(&mut self) -> Result<(), String> {
+        let width = self.width.ok_or("missing WIDTH")?;
+        let depth = self.depth.ok_or("missing DEPTH")?;
+        self.mif = Some(Mif {
+            address_radix: self.address_radix.unwrap_or(Radix::Hex),
+            data_radix: self.data_radix.unwrap_or(Radix::Hex),
+            ..Mif::new(width, depth)
+        });
+        Ok(())
+    }
+
+    /// `addr: word word ...` fills from `addr` on, `[first..last]: word ...` repeats the
+    /// words over the range.
+    fn entry(&mut self, entry: &str, line: usize) -> Result<(), String> {
+        let mif = self.mif.as_mut().unwrap();
+        let (address, data) = entry
+            .split_once(':')
+            .ok_or_else(|| format!("expected `address: data`, found `{}`", entry))?;
+        let words = data
+            .split_whitespace()
+            .map(|word| parse_word(word, mif.data_radix, mif.width))
+            .collect::<Result<Vec<_>, _>>()?;
+        if words.is_empty() {
+            return Err(format!("no data for `{}`", address.trim()));
+        }
+        let address = address.trim();
+        let address = match address
+            .strip_prefix('[')
+            .and_then(|range| range.strip_suffix(']'))
+        {
+            Some(range) => {
+                let (first, last) = range
+                    .split_once("..")
+                    .ok_or_else(|| format!("invalid range `{}`", address))?;
+                let first = parse_address(first.trim(), mif.address_radix)?;
+                let last = parse_address(last.trim(), mif.address_radix)?;
+                if last < first {
+                    return Err(format!("invalid range `{}`", address));
+                }
+                Address::Range(first, last)
+            }
+            None => Address::At(parse_address(address, mif.address_radix)?),
+        };
+        let entry = Entry {
+            address,
+            words,
+            line,
+        };
+        let end = entry.addresses().end;
+        if end > mif.depth {
+            return Err(format!(
+                "address {} is past DEPTH {}",
+                mif.address_radix.format(end as u64 - 1, 1),
+                mif.depth
+            ));
+        }
+        mif.entries.push(entry);
+        Ok(())
+    }
+}
+
+fn parse_address(address: &str, radix: Radix) -> Result<usize, String> {
+    usize::from_str_radix(address, radix.base())
+        .map_err(|_| format!("invalid address `{}`", address))
+}
+
+fn parse_word(word: &str, radix: Radix, width: u32) -> Result<u64, String> {
+    let invalid = || format!("invalid data `{}`", word);
+    let max = match width {
+        64 => u64::MAX,
+        width => (1 << width) - 1,
+    };
+    let value = match (radix, word.strip_prefix('-')) {
+        // two's complement in `width` bits.
+        (Radix::Dec, Some(magnitude)) => {
+            let magnitude: u64 = magnitude.parse().map_err(|_| invalid())?;
+            if magnitude == 0 || magnitude - 1 > max >> 1 {
+                return Err(format!("data `{}` does not fit in {} bits", word, width));
+            }
+            (!magnitude).wrapping_add(1) & max
+        }
+        _ => u64::from_str_radix(word, radix.base()).map_err(|_| invalid())?,
+    };
+    if value > max {
+        return Err(format!("data `{}` does not fit in {} bits", word, width));
+    }
+    Ok(value)
+}
+
+/// `statement` without the leading `keywords`, compared case-insensitively.
+fn strip_keywords<'a>(statement: &'a str, keywords: &[&str]) -> Option<&'a str> {
+    let mut rest = statement;
+    for keyword in keywords {
+        let trimmed = rest.trim_start();
+        let head = trimmed.get(..keyword.len())?;
+        if !head.eq_ignore_ascii_case(keyword) {
+            return None;
+        }
+        rest = &trimmed[keyword.len()..];
+        if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+    }
+    Some(rest)
+}
+
+/// Replaces comments with spaces, keeping newlines so that line numbers still match.
+fn strip_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        stripped.push('\n');
+                        break;
+                    }
+                }
+            }
+            '%' => {
+                for c in chars.by_ref() {
+                    match c {
+                        '%' => break,
+                        '\n' => stripped.push('\n'),
+                        _ => {}
+                    }
+                }
+            }
+            c => stripped.push(c),
+        }
+    }
+    stripped
+}
+
+fn leading_newlines(statement: &str) -> usize {
+    statement
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .filter(|&c| c == '\n')
+        .count()
+}
+
+/// Block ram of the FPGA that can be initialized from a .mif.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ip {
+    pub name: &'static str,
+    pub width: u32,
+    pub depth: usize,
+}
+
+/// `numwords_a` of the rams generated in `veryls/FC2/HDL/cpu/vendor/cpu` and the pattern
+/// tables in `vendor/ppu`.
+pub const IPS: &[Ip] = &[
+    Ip::new("ip_nestest", 16384),
+    Ip::new("ip_prg1_rom", 16384),
+    Ip::new("ip_prg2_rom", 16384),
+    Ip::new("ip_eram", 8192),
+    Ip::new("ip_erom", 8160),
+    Ip::new("ip_wram", 4096),
+    Ip::new("ip_wram_mirror", 6144),
+    Ip::new("ip_ppu_reg_mirror", 6144),
+    Ip::new("ip_func_apu_io", 8),
+    Ip::new("ip_pattern_table_00", 4096),
+    Ip::new("ip_pattern_table_01", 4096),
+];
+
+impl Ip {
+    const fn new(name: &'static str, depth: usize) -> Self {
+        Self {
+            name,
+            width: 8,
+            depth,
+        }
+    }
+
+    pub fn find(name: &str) -> Option<&'static Ip> {
+        IPS.iter().find(|ip| ip.name == name)
+    }
+}
@@ -0,0 +1,203 @@
+use mif::ines::{self, Rom};
+use mif::{Ip, Mif, IPS};
+use std::{env, fs, path::Path, process};
+
+const USAGE: &str = "usage: mif check <file> [--ip <name>]
+       mif to-bin <file> <out>
+       mif from-bin <bin> <out> [--ip <name> | --depth <n>]
+       mif from-nes <rom.nes> <dir>
+       mif ips";
+
+enum Command {
+    /// Parses a file and checks it against an ip, by default `ip_nestest`.
+    Check {
+        path: String,
+        ip: &'static Ip,
+    },
+    /// Writes the words of an 8 bit wide file as raw bytes.
+    ToBin {
+        path: String,
+        out: String,
+    },
+    /// Writes raw bytes as a file in the layout of `hex_init_files/nestest.mif`.
+    FromBin {
+        path: String,
+        out: String,
+        depth: Option<usize>,
+    },
+    /// Writes a .mif for each ip a cartridge fills into a directory.
+    FromNes {
+        path: String,
+        dir: String,
+    },
+    Ips,
+}
+
+fn find_ip(name: &str) -> Result<&'static Ip, String> {
+    Ip::find(name).ok_or_else(|| format!("unknown ip `{}`, see `mif ips`", name))
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let (command, args) = args.split_first().ok_or("command is missing")?;
+    let mut paths = vec![];
+    let mut ip = None;
+    let mut depth = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ip" => ip = Some(find_ip(args.next().ok_or("--ip needs a name")?)?),
+            "--depth" => {
+                let value = args.next().ok_or("--depth needs a value")?;
+                match value.parse() {
+                    Ok(n) if n > 0 => depth = Some(n),
+                    _ => return Err(format!("invalid --depth `{}`", value)),
+                }
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{}`", arg)),
+            _ => paths.push(arg.clone()),
+        }
+    }
+    let mut paths = paths.into_iter();
+    let mut path = |what: &str| paths.next().ok_or(format!("{} is missing", what));
+    let command = match command.as_str() {
+        "check" => Command::Check {
+            path: path("file")?,
+            ip: match ip {
+                Some(ip) => ip,
+                None => find_ip("ip_nestest")?,
+            },
+        },
+        "to-bin" => Command::ToBin {
+            path: path("file")?,
+            out: path("output")?,
+        },
+        "from-bin" => Command::FromBin {
+            path: path("bin")?,
+            out: path("output")?,
+            depth: depth.or(ip.map(|ip| ip.depth)),
+        },
+        "from-nes" => Command::FromNes {
+            path: path("rom")?,
+            dir: path("directory")?,
+        },
+        "ips" => Command::Ips,
+        _ => return Err(format!("unknown command `{}`", command)),
+    };
+    match paths.next() {
+        Some(arg) => Err(format!("unexpected argument `{}`", arg)),
+        None => Ok(command),
+    }
+}
+
+fn read_mif(path: &str) -> Mif {
+    let source =
+        fs::read_to_string(path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e));
+    Mif::parse(&source).unwrap_or_else(|e| {
+        eprintln!("{}:{}", path, e);
+        process::exit(1);
+    })
+}
+
+fn write(path: &str, content: impl AsRef<[u8]>) {
+    fs::write(path, content).unwrap_or_else(|e| panic!("failed to write {}: {}", path, e));
+    println!("{} written.", path);
+}
+
+fn print_header(header: &ines::Header) {
+    println!(
+        "{:?} mapper {} ({}) submapper {}, PRG-ROM {} bytes, CHR-ROM {} bytes",
+        header.format,
+        header.mapper,
+        ines::mapper_name(header.mapper),
+        header.submapper,
+        header.prg_rom_len,
+        header.chr_rom_len
+    );
+    println!(
+        "{:?} mirroring, battery {}, trainer {}",
+        header.mirroring, header.battery, header.trainer
+    );
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    match command {
+        Command::Check { path, ip } => {
+            let mif = read_mif(&path);
+            println!(
+                "WIDTH={} DEPTH={} ADDRESS_RADIX={} DATA_RADIX={}",
+                mif.width,
+                mif.depth,
+                mif.address_radix.as_str(),
+                mif.data_radix.as_str()
+            );
+            for overwrite in mif.overwrites() {
+                println!(
+                    "line {} overwrites {} words from {:x} written on line {}.",
+                    overwrite.line,
+                    overwrite.addresses.len(),
+                    overwrite.addresses.start,
+                    overwrite.previous_line
+                );
+            }
+            match mif.unwritten() {
+                0 => {}
+                unwritten => println!("{} words are not written and read as 0.", unwritten),
+            }
+            if let Err(e) = mif.check(ip) {
+                eprintln!("{}: {}", path, e);
+                process::exit(1);
+            }
+            println!("{} fits {}.", path, ip.name);
+        }
+        Command::ToBin { path, out } => {
+            let bytes = read_mif(&path).to_bytes().unwrap_or_else(|| {
+                eprintln!("{}: only 8 bit wide files can be written as bytes", path);
+                process::exit(1);
+            });
+            write(&out, bytes);
+        }
+        Command::FromBin { path, out, depth } => {
+            let bytes =
+                fs::read(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e));
+            let depth = depth.unwrap_or(bytes.len());
+            let mif = Mif::from_bytes(&bytes, depth).unwrap_or_else(|| {
+                eprintln!(
+                    "{} has {} bytes, more than DEPTH {}",
+                    path,
+                    bytes.len(),
+                    depth
+                );
+                process::exit(1);
+            });
+            write(&out, mif.to_string());
+        }
+        Command::FromNes { path, dir } => {
+            let bytes =
+                fs::read(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e));
+            let images = Rom::parse(&bytes)
+                .and_then(|rom| {
+                    print_header(&rom.header);
+                    rom.images()
+                })
+                .unwrap_or_else(|e| {
+                    eprintln!("{}: {}", path, e);
+                    process::exit(1);
+                });
+            fs::create_dir_all(&dir).unwrap_or_else(|e| panic!("failed to create {}: {}", dir, e));
+            for image in images {
+                let out = Path::new(&dir).join(format!("{}.mif", image.ip.name));
+                write(&out.display().to_string(), image.mif.to_string());
+            }
+        }
+        Command::Ips => {
+            for ip in IPS {
+                println!("{:<18} {:>2} bits {:>6} words", ip.name, ip.width, ip.depth);
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use mif::ines::{
+    Error, Format, Header, Mirroring, Rom, CHR_BANK_LEN, HEADER_LEN, MAGIC, PRG_BANK_LEN,
+};
+
+fn header(bytes: [u8; 12]) -> [u8; HEADER_LEN] {
+    let mut header = [0x0u8; HEADER_LEN];
+    header[..4].copy_from_slice(&MAGIC);
+    header[4..].copy_from_slice(&bytes);
+    header
+}
+
+fn rom(header: [u8; HEADER_LEN], len: usize) -> Vec<u8> {
+    let mut bytes = header.to_vec();
+    bytes.extend((0..len).map(|i| i as u8));
+    bytes
+}
+
+#[test]
+fn parses_ines_header() {
+    let header = Header::parse(&header([2, 1, 0x13, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+    assert_eq!(header.format, Format::INes);
+    assert_eq!(header.mapper, 1);
+    assert_eq!(header.prg_rom_len, 2 * PRG_BANK_LEN);
+    assert_eq!(header.chr_rom_len, CHR_BANK_LEN);
+    assert_eq!(header.mirroring, Mirroring::Vertical);
+    assert!(header.battery);
+    assert!(!header.trainer);
+}
+
+#[test]
+fn ignores_mapper_nibble_under_a_dumper_name() {
+    let mut bytes = header([1, 1, 0x00, 0x40, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Header::parse(&bytes).unwrap().mapper, 0x40);
+    bytes[7..].copy_from_slice(b"DiskDude!");
+    assert_eq!(Header::parse(&bytes).unwrap().mapper, 0);
+}
+
+#[test]
+fn parses_nes2_header() {
+    let header = Header::parse(&header([
+        0x02, 0x00, 0x48, 0x28, 0x31, 0x01, 0, 0, 0, 0, 0, 0,
+    ]))
+    .unwrap();
+    assert_eq!(header.format, Format::Nes2);
+    assert_eq!(header.mapper, 0x124);
+    assert_eq!(header.submapper, 3);
+    assert_eq!(header.prg_rom_len, 0x102 * PRG_BANK_LEN);
+    assert_eq!(header.chr_rom_len, 0);
+    assert_eq!(header.mirroring, Mirroring::FourScreen);
+}
+
+#[test]
+fn parses_nes2_exponent_sizes() {
+    // 2^4 * 3 bytes of PRG-ROM and 2^10 * 1 of CHR-ROM.
+    let header =
+        Header::parse(&header([0x11, 0x28, 0x00, 0x08, 0, 0xFF, 0, 0, 0, 0, 0, 0])).unwrap();
+    assert_eq!(header.prg_rom_len, 48);
+    assert_eq!(header.chr_rom_len, 1024);
+}
+
+#[test]
+fn rejects_sizes_that_overflow() {
+    let bytes = header([0xFF, 0x00, 0x00, 0x08, 0x00, 0x0F, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Header::parse(&bytes), Err(Error::RomSize));
+    assert_eq!(Rom::parse(&bytes), Err(Error::RomSize));
+
+    // each size fits, but not both together.
+    let bytes = header([0xFC, 0xFC, 0x00, 0x08, 0x00, 0xFF, 0, 0, 0, 0, 0, 0]);
+    if usize::BITS == 64 {
+        assert_eq!(Header::parse(&bytes).unwrap().prg_rom_len, 1 << 63);
+    }
+    assert_eq!(Rom::parse(&bytes), Err(Error::RomSize));
+}
+
+#[test]
+fn splits_rom_after_the_trainer() {
+    let bytes = rom(
+        header([1, 1, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+        512 + PRG_BANK_LEN + CHR_BANK_LEN,
+    );
+    let rom = Rom::parse(&bytes).unwrap();
+    let prg_start = HEADER_LEN + 512;
+    assert_eq!(rom.prg_rom, bytes[prg_start..prg_start + PRG_BANK_LEN]);
+    assert_eq!(rom.chr_rom, bytes[prg_start + PRG_BANK_LEN..]);
+
+    let images = rom.images().unwrap();
+    let names: Vec<&str> = images.iter().map(|image| image.ip.name).collect();
+    assert_eq!(
+        names,
+        [
+            "ip_prg1_rom",
+            "ip_prg2_rom",
+            "ip_pattern_table_00",
+            "ip_pattern_table_01"
+        ]
+    );
+    // NROM-128 mirrors its only bank at $C000.
+    assert_eq!(images[0].mif, images[1].mif);
+}
+
+#[test]
+fn rejects_short_files_and_other_mappers() {
+    let bytes = rom(header([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]), 100);
+    assert_eq!(
+        Rom::parse(&bytes),
+        Err(Error::Truncated {
+            expected: HEADER_LEN + PRG_BANK_LEN + CHR_BANK_LEN,
+            actual: HEADER_LEN + 100
+        })
+    );
+    assert_eq!(
+        Rom::parse(b"NES"),
+        Err(Error::Truncated {
+            expected: HEADER_LEN,
+            actual: 3
+        })
+    );
+    assert_eq!(Rom::parse(&[0x0u8; HEADER_LEN]), Err(Error::Magic));
+
+    let bytes = rom(
+        header([1, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+        PRG_BANK_LEN,
+    );
+    let rom = Rom::parse(&bytes).unwrap();
+    assert_eq!(rom.images(), Err(Error::UnsupportedMapper(1)));
+}
@@ -0,0 +1,52 @@
+use mif::{Ip, Mif};
+
+const NESTEST_MIF: &str = "../../hex_init_files/nestest.mif";
+
+fn nestest() -> (String, Mif) {
+    let source = std::fs::read_to_string(NESTEST_MIF).unwrap();
+    let mif = Mif::parse(&source).unwrap();
+    (source, mif)
+}
+
+#[test]
+fn nestest_mif_round_trips_byte_for_byte() {
+    let (source, mif) = nestest();
+    assert_eq!(mif.to_string(), source);
+    assert_eq!(Mif::parse(&mif.to_string()).unwrap(), mif);
+}
+
+#[test]
+fn nestest_mif_bytes_round_trip() {
+    let (_, mif) = nestest();
+    let bytes = mif.to_bytes().unwrap();
+    assert_eq!(bytes.len(), 0x4000);
+    assert_eq!(bytes[..3], [0x4C, 0xF5, 0xC5]);
+    // the reset vector at $FFFC.
+    assert_eq!(bytes[0x3FFC..0x3FFE], [0x04, 0xC0]);
+    let rebuilt = Mif::from_bytes(&bytes, mif.depth).unwrap();
+    assert_eq!(rebuilt.to_bytes().unwrap(), bytes);
+    let reparsed = Mif::parse(&rebuilt.to_string()).unwrap();
+    assert_eq!(reparsed.to_bytes().unwrap(), bytes);
+    assert!(reparsed.overwrites().is_empty());
+}
+
+#[test]
+fn nestest_mif_fills_ip_nestest() {
+    let (_, mif) = nestest();
+    assert_eq!(mif.check(Ip::find("ip_nestest").unwrap()), Ok(()));
+    assert!(mif.check(Ip::find("ip_eram").unwrap()).is_err());
+    // two lines of the file carry the address of an earlier one, so 32 words stay 0.
+    let overwrites: Vec<(usize, usize, usize)> = mif
+        .overwrites()
+        .iter()
+        .map(|overwrite| {
+            (
+                overwrite.line,
+                overwrite.previous_line,
+                overwrite.addresses.start,
+            )
+        })
+        .collect();
+    assert_eq!(overwrites, [(992, 983, 0x3D10), (1029, 1013, 0x3FF0)]);
+    assert_eq!(mif.unwritten(), 32);
+}
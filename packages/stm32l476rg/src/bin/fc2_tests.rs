@@ -0,0 +1,38 @@
+#![no_std]
+#![no_main]
+
+use embassy_fc2_app::middleware::runner::TestRunner;
+use embassy_stm32::dma::NoDma;
+use embassy_stm32::gpio::{Input, Level, Output, Pull, Speed};
+use embassy_stm32::usart::{Config, Uart};
+use embassy_stm32::{bind_interrupts, peripherals, usart};
+use stm32l476rg::pin::util::*;
+use {defmt_rtt as _, panic_probe as _};
+
+bind_interrupts!(struct Irqs {
+    USART1 => usart::InterruptHandler<peripherals::USART1>;
+});
+
+/// Comma separated suites to run, given when building. Every suite runs when it is unset.
+///
+/// Another subset needs another build, see `fc2_suites::registry`.
+const FC2_SUITES: Option<&str> = option_env!("FC2_SUITES");
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    let p = embassy_stm32::init(Default::default());
+    let config = Config::default();
+    let usart = Uart::new_with_rtscts(
+        p.USART1, p.PA10, p.PA9, Irqs, p.PA12, p.PA11, NoDma, NoDma, config,
+    )
+    .unwrap();
+    let rw = Input::new(p.PA0, Pull::None);
+    let nop = Input::new(p.PA1, Pull::None);
+    let resb = Output::new(p.PA4, Level::Low, Speed::Medium);
+    let mut fc2 = Fc2Port::new(usart, rw, nop, resb);
+    let mut runner = TestRunner::new();
+    runner.run_suites(&mut fc2, &fc2_suites::registry(), FC2_SUITES);
+
+    runner.finish();
+    loop {}
+}
@@ -1 +1,2 @@
+pub mod port;
 pub mod util;
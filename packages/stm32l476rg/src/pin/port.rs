@@ -0,0 +1,56 @@
+use embassy_fc2_app::middleware::transport::{ControlPins, Transport};
+use embassy_stm32::gpio::{Input, Output, Pin};
+use embassy_stm32::usart::{BasicInstance, Error, Uart};
+
+/// Usart and control pins wired to the FPGA.
+pub struct Fc2Port<'d, T: BasicInstance, P: Pin, P2: Pin, P3: Pin> {
+    usart: Uart<'d, T>,
+    rw: Input<'d, P>,
+    nop: Input<'d, P2>,
+    resb: Output<'d, P3>,
+}
+
+impl<'d, T: BasicInstance, P: Pin, P2: Pin, P3: Pin> Fc2Port<'d, T, P, P2, P3> {
+    pub fn new(
+        usart: Uart<'d, T>,
+        rw: Input<'d, P>,
+        nop: Input<'d, P2>,
+        resb: Output<'d, P3>,
+    ) -> Self {
+        Self {
+            usart,
+            rw,
+            nop,
+            resb,
+        }
+    }
+}
+
+impl<T: BasicInstance, P: Pin, P2: Pin, P3: Pin> Transport for Fc2Port<'_, T, P, P2, P3> {
+    type Error = Error;
+
+    fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.usart.blocking_write(data)
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        self.usart.blocking_read(buf)
+    }
+}
+
+impl<T: BasicInstance, P: Pin, P2: Pin, P3: Pin> ControlPins for Fc2Port<'_, T, P, P2, P3> {
+    fn is_rw_high(&mut self) -> bool {
+        self.rw.is_high()
+    }
+
+    fn is_nop_high(&mut self) -> bool {
+        self.nop.is_high()
+    }
+
+    fn set_resb(&mut self, high: bool) {
+        match high {
+            true => self.resb.set_high(),
+            false => self.resb.set_low(),
+        }
+    }
+}
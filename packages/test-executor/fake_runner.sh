@@ -0,0 +1,51 @@
+#!/bin/sh
+# Prints what fc2_tests logs through probe-run, without a board.
+#
+# Every suite in FC2_SUITES passes one test, except the ones listed in FAKE_FAIL, which fail
+# a register check, and FAKE_HANG, which stops logging. FAKE_CRASH exits in the middle of a
+# suite, and FAKE_EXIT exits right before it starts, as when probe-run loses the board.
+#
+#   cargo run -- --runner ./fake_runner.sh --env FAKE_FAIL=ld --env FAKE_HANG=st --timeout 2
+
+contains() {
+    case ",$1," in *",$2,"*) return 0 ;; esac
+    return 1
+}
+
+echo "(HOST) INFO  flashing program (fake)"
+failed=0
+for suite in $(echo "$FC2_SUITES" | tr ',' ' '); do
+    if contains "$FAKE_EXIT" "$suite"; then
+        echo "(HOST) ERROR probe lost (fake)"
+        exit 1
+    fi
+    echo "INFO  suite $suite started."
+    echo "└─ embassy_fc2_app::middleware::runner::{impl#1}::run_suite @ src/middleware/runner.rs:93"
+    if contains "$FAKE_HANG" "$suite"; then
+        exec sleep 3600
+    fi
+    if contains "$FAKE_CRASH" "$suite"; then
+        echo "ERROR panicked at 'fake crash'"
+        exit 1
+    fi
+    echo 'INFO  inst LDA #$FF'
+    echo "INFO  usart write data(0xA9)"
+    if contains "$FAKE_FAIL" "$suite"; then
+        echo "INFO  test failed. expect value is [0xA4], but return value is [0x24]"
+        echo "INFO  expected N set, got N clear"
+        echo "INFO  test_${suite} failed at register P. expect value is [A4], but return value is [24]. expected N set, got N clear"
+        echo "INFO  test test_${suite} failed."
+        echo "INFO  suite $suite failed."
+        failed=$((failed + 1))
+    else
+        echo "INFO  test test_${suite} passed."
+        echo "INFO  suite $suite passed."
+    fi
+done
+if [ "$failed" -gt 0 ]; then
+    echo "ERROR panicked at '$failed tests failed'"
+    exit 1
+fi
+echo "INFO  all tests passed!"
+# probe-run keeps running after the firmware finishes.
+exec sleep 3600
@@ -0,0 +1,92 @@
+//! Flashes `fc2_tests` through a [`Runner`] and collects what its suites log into a
+//! [`Report`].
+
+pub mod report;
+pub mod runner;
+pub mod suites;
+
+use report::{Collector, Report, Status};
+use runner::Runner;
+use std::os::unix::process::CommandExt;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::time::{timeout_at, Instant};
+
+/// Time for cargo to build and probe-run to flash before the first suite starts.
+const STARTUP_LIMIT: Duration = Duration::from_secs(120);
+
+/// Flashes the firmware with `suites` and collects their results until it finishes, exits,
+/// or a suite runs longer than `limit`.
+async fn exe_suites(
+    collector: &mut Collector,
+    runner: &Runner,
+    suites: &[String],
+    limit: Duration,
+) {
+    let suites = suites.join(",");
+    println!("{} started.", suites);
+    let mut command = runner.command(&suites);
+    // probe-run runs under cargo, so both are killed through the group.
+    command.process_group(0);
+    let mut test_process = Command::from(command)
+        .kill_on_drop(true)
+        .spawn()
+        .unwrap_or_else(|e| panic!("failed to start {}: {}", runner.program, e));
+    let mut lines = BufReader::new(test_process.stdout.take().unwrap()).lines();
+    let mut deadline = Instant::now() + STARTUP_LIMIT;
+    loop {
+        match timeout_at(deadline, lines.next_line()).await {
+            Ok(Ok(Some(line))) => {
+                let running = collector.running().map(str::to_string);
+                collector.line(&line);
+                if line.contains("all tests passed") {
+                    break;
+                }
+                if collector.running().is_some() && collector.running() != running.as_deref() {
+                    deadline = Instant::now() + limit;
+                }
+            }
+            Ok(_) => {
+                collector.abort(Status::Failed, "runner exited.".to_string());
+                break;
+            }
+            Err(_) => {
+                let message = format!("no result within {} seconds.", limit.as_secs());
+                collector.abort(Status::Timeout, message);
+                break;
+            }
+        }
+    }
+    // probe-run keeps running after the firmware finishes, and a hung one never exits.
+    if let Some(pid) = test_process.id() {
+        // SAFETY: only signals the group spawned above.
+        unsafe { libc::kill(-(pid as i32), libc::SIGKILL) };
+    }
+    let _ = test_process.kill().await;
+}
+
+/// Runs every suite in `suites`, flashing again with the rest whenever one hangs or takes
+/// probe-run down with it.
+pub async fn exe_all(runner: &Runner, suites: Vec<String>, limit: Duration) -> Report {
+    let mut collector = Collector::new();
+    let mut pending = suites;
+    while !pending.is_empty() {
+        exe_suites(&mut collector, runner, &pending, limit).await;
+        let finished: Vec<&str> = collector.finished().collect();
+        let rest: Vec<String> = pending
+            .iter()
+            .filter(|name| !finished.contains(&name.as_str()))
+            .cloned()
+            .collect();
+        if rest.len() == pending.len() {
+            // nothing finished, so another flash would not get further.
+            for name in &pending {
+                collector.skip(name, Status::Failed, "did not run.".to_string());
+            }
+            break;
+        }
+        pending = rest;
+    }
+    collector.finish()
+}
@@ -1,52 +1,207 @@
 use std::time::Duration;
 use std::{
-    env,
-    io::{BufRead, BufReader},
-    path::Path,
-    process::{Command, Stdio},
+    env, fs,
+    path::{Path, PathBuf},
+    process,
 };
-use tokio::time::timeout;
-
-async fn exe_testcase(testcase: String) {
-    println!("{} started.", testcase);
-    let mut test_process = Command::new("cargo")
-        .arg("run")
-        .arg("--bin")
-        .arg(&testcase)
-        .stdout(Stdio::piped())
-        .spawn()
-        .unwrap();
-    let reader = BufReader::new(test_process.stdout.as_mut().unwrap());
-    let line = reader
-        .lines()
-        .map_while(Result::ok)
-        .find(|line| line.contains("all tests passed"));
-    if line.is_some() {
-        println!("{} passed.", testcase);
-        test_process.kill().unwrap();
-        return;
+use test_executor::exe_all;
+use test_executor::report::{json, junit, Report, Status};
+use test_executor::runner::Runner;
+use test_executor::suites::{self, Selection};
+
+const USAGE: &str = "usage: test-executor [--list] [--exclude <glob>]... [--generated] \
+    [--junit <path>] [--json <path>] [--logs <dir>] [--timeout <secs>] \
+    [--runner <command>] [--env <key=value>]... [suite or glob...]";
+
+struct Args {
+    selection: Selection,
+    /// Prints the selected suites instead of running them.
+    list: bool,
+    junit: Option<PathBuf>,
+    json: Option<PathBuf>,
+    /// Directory that gets one `<suite>.log` per suite.
+    logs: PathBuf,
+    /// Longest a suite may run before probe-run is killed.
+    timeout: Duration,
+    runner: Runner,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut parsed = Args {
+        selection: Selection::default(),
+        list: false,
+        junit: None,
+        json: None,
+        logs: "target/fc2-logs".into(),
+        // as long as each suite had when it was flashed on its own.
+        timeout: Duration::from_secs(14),
+        runner: Runner::default(),
+    };
+    let mut env = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--junit" => parsed.junit = Some(args.next().ok_or("--junit needs a path")?.into()),
+            "--json" => parsed.json = Some(args.next().ok_or("--json needs a path")?.into()),
+            "--logs" => parsed.logs = args.next().ok_or("--logs needs a path")?.into(),
+            "--list" => parsed.list = true,
+            "--generated" => parsed.selection.generated = true,
+            "--exclude" => parsed
+                .selection
+                .exclude
+                .push(args.next().ok_or("--exclude needs a pattern")?.clone()),
+            "--runner" => {
+                parsed.runner = Runner::parse(args.next().ok_or("--runner needs a command")?)?
+            }
+            "--env" => env.push(args.next().ok_or("--env needs a value")?),
+            "--timeout" => {
+                let value = args.next().ok_or("--timeout needs a value")?;
+                match value.parse() {
+                    Ok(secs) if secs > 0 => parsed.timeout = Duration::from_secs(secs),
+                    _ => return Err(format!("invalid --timeout `{}`", value)),
+                }
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{}`", arg)),
+            _ => parsed.selection.include.push(arg.clone()),
+        }
+    }
+    // applies to whichever runner was given, before or after `--env`.
+    for var in env {
+        parsed.runner.push_env(var)?;
+    }
+    Ok(parsed)
+}
+
+fn write_report(path: &Path, content: String) {
+    fs::write(path, content)
+        .unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
+    println!("report written to {}.", path.display());
+}
+
+/// Keeps the whole probe-run output of every suite, since the summary only shows the check
+/// that failed.
+fn write_logs(dir: &Path, report: &Report) {
+    fs::create_dir_all(dir).unwrap_or_else(|e| panic!("failed to create {}: {}", dir.display(), e));
+    for suite in &report.suites {
+        let path = dir.join(format!("{}.log", suite.name));
+        let mut log = suite.log.join("\n");
+        log.push('\n');
+        fs::write(&path, log)
+            .unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
     }
-    panic!("{} failed", testcase);
+    println!("logs written to {}.", dir.display());
+}
+
+fn print_summary(report: &Report) {
+    println!(
+        "{:<16} {:<8} {:>6} {:>6} {:>9}",
+        "suite", "result", "tests", "failed", "time"
+    );
+    for suite in &report.suites {
+        println!(
+            "{:<16} {:<8} {:>6} {:>6} {:>8.2}s",
+            suite.name,
+            suite.status.as_str(),
+            suite.cases.len(),
+            suite.failures(),
+            suite.duration.as_secs_f64()
+        );
+    }
+    for suite in &report.suites {
+        for case in suite
+            .cases
+            .iter()
+            .filter(|case| case.status != Status::Passed)
+        {
+            match (&case.mismatch, &case.message) {
+                (Some(mismatch), _) => println!(
+                    "  {}::{} {}: expected {}, got {}",
+                    suite.name,
+                    case.name,
+                    mismatch.check.as_deref().unwrap_or("check"),
+                    mismatch.expected,
+                    mismatch.actual
+                ),
+                (None, Some(message)) => println!("  {}::{} {}", suite.name, case.name, message),
+                (None, None) => println!("  {}::{}", suite.name, case.name),
+            }
+            if let Some(flags) = case.mismatch.as_ref().and_then(|m| m.flags.as_ref()) {
+                println!("    {}", flags);
+            }
+            if let Some(instruction) = &case.instruction {
+                println!("    at {}", instruction);
+            }
+        }
+    }
+    println!(
+        "{} suites, {} tests, {} failed.",
+        report.suites.len(),
+        report.tests(),
+        report.failures()
+    );
 }
 
 #[tokio::main]
 async fn main() {
-    let root = Path::new("../stm32l476rg/src/bin");
-    env::set_current_dir(root).unwrap();
-    let stdout = Command::new("ls").output().unwrap().stdout;
-    let raw_stdout = String::from_utf8_lossy(&stdout);
-    let testcases: Vec<&str> = raw_stdout
-        .split(".rs\n")
-        .filter(|testcase| !testcase.is_empty())
-        .collect();
-    let root = Path::new("../");
+    // suite names or globs as registered in fc2_suites, e.g. `cargo run -- adc 'l*'`.
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    // reports are written relative to where the executor was started.
+    let cwd = env::current_dir().unwrap();
+    let junit_path = args.junit.map(|path| cwd.join(path));
+    let json_path = args.json.map(|path| cwd.join(path));
+    let logs_path = cwd.join(args.logs);
+    let mut runner = args.runner;
+    // a runner given as a path is relative to the start directory as well.
+    if runner.program.contains('/') {
+        runner.program = cwd.join(&runner.program).display().to_string();
+    }
+    let root = Path::new("../stm32l476rg");
     env::set_current_dir(root).unwrap();
 
-    let timelimit = Duration::from_secs(14);
-    for testcase in testcases {
-        timeout(timelimit, exe_testcase(testcase.to_string()))
-            .await
-            .unwrap();
+    let registered = suites::discover(Path::new("../fc2_suites")).unwrap_or_else(|e| {
+        eprintln!("failed to read the suite registry: {}", e);
+        process::exit(2);
+    });
+    let selected = args.selection.select(&registered).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    if args.list {
+        for name in &registered {
+            let mark = if selected.contains(&name.as_str()) {
+                "*"
+            } else {
+                " "
+            };
+            let note = if suites::is_generated(name) {
+                " (generated)"
+            } else {
+                ""
+            };
+            println!("{} {}{}", mark, name, note);
+        }
+        return;
+    }
+    if selected.is_empty() {
+        eprintln!("no suite selected.");
+        process::exit(2);
+    }
+    let suites = selected.into_iter().map(str::to_string).collect();
+    let report = exe_all(&runner, suites, args.timeout).await;
+    write_logs(&logs_path, &report);
+    if let Some(path) = junit_path {
+        write_report(&path, junit::render(&report));
+    }
+    if let Some(path) = json_path {
+        write_report(&path, json::render(&report));
+    }
+    print_summary(&report);
+    if !report.passed() || report.suites.is_empty() {
+        process::exit(1);
     }
     println!("all test passed.");
 }
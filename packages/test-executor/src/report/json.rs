@@ -0,0 +1,152 @@
+use super::Report;
+use std::fmt::Write;
+
+/// Same content as the JUnit report, with durations in seconds.
+pub fn render(report: &Report) -> String {
+    let mut json = String::new();
+    writeln!(
+        json,
+        "{{\n  \"tests\": {},\n  \"failures\": {},\n  \"duration\": {:.3},\n  \"suites\": [",
+        report.tests(),
+        report.failures(),
+        report.duration().as_secs_f64()
+    )
+    .unwrap();
+    for (i, suite) in report.suites.iter().enumerate() {
+        writeln!(
+            json,
+            "    {{\n      \"name\": {},\n      \"status\": \"{}\",\n      \"duration\": {:.3},\n      \"cases\": [",
+            string(&suite.name),
+            suite.status.as_str(),
+            suite.duration.as_secs_f64()
+        )
+        .unwrap();
+        for (j, case) in suite.cases.iter().enumerate() {
+            let message = case.message.as_deref().map_or("null".to_string(), string);
+            write!(
+                json,
+                "        {{ \"name\": {}, \"status\": \"{}\", \"duration\": {:.3}, \"message\": {}",
+                string(&case.name),
+                case.status.as_str(),
+                case.duration.as_secs_f64(),
+                message
+            )
+            .unwrap();
+            if let Some(mismatch) = &case.mismatch {
+                let check = mismatch.check.as_deref().map_or("null".to_string(), string);
+                write!(
+                    json,
+                    ", \"check\": {}, \"expected\": {}, \"actual\": {}",
+                    check,
+                    string(&mismatch.expected),
+                    string(&mismatch.actual)
+                )
+                .unwrap();
+                if let Some(flags) = &mismatch.flags {
+                    write!(json, ", \"flags\": {}", string(flags)).unwrap();
+                }
+            }
+            if let Some(instruction) = &case.instruction {
+                write!(json, ", \"instruction\": {}", string(instruction)).unwrap();
+            }
+            json.push_str(" }");
+            json.push_str(if j + 1 < suite.cases.len() {
+                ",\n"
+            } else {
+                "\n"
+            });
+        }
+        json.push_str("      ]\n    }");
+        json.push_str(if i + 1 < report.suites.len() {
+            ",\n"
+        } else {
+            "\n"
+        });
+    }
+    json.push_str("  ]\n}\n");
+    json
+}
+
+fn string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::report;
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(string("LDA #$FF"), r#""LDA #$FF""#);
+        assert_eq!(string(r#"a "b" \c"#), r#""a \"b\" \\c""#);
+        assert_eq!(string("a\nb\rc\td"), r#""a\nb\rc\td""#);
+        assert_eq!(string("\u{1}\u{1f}<&é"), r#""\u0001\u001f<&é""#);
+    }
+
+    #[test]
+    fn renders_every_status() {
+        let expected = r#"{
+  "tests": 5,
+  "failures": 3,
+  "duration": 4.750,
+  "suites": [
+    {
+      "name": "adc",
+      "status": "passed",
+      "duration": 1.500,
+      "cases": [
+        { "name": "test_adc", "status": "passed", "duration": 1.500, "message": null }
+      ]
+    },
+    {
+      "name": "lda",
+      "status": "failed",
+      "duration": 1.250,
+      "cases": [
+        { "name": "test_ok", "status": "passed", "duration": 0.250, "message": null },
+        { "name": "test_lda", "status": "failed", "duration": 1.000, "message": "test_lda failed at register P. expect value is [A4], but return value is [24]. expected N set, got N clear", "check": "register P", "expected": "[A4]", "actual": "[24]", "flags": "expected N set, got N clear", "instruction": "LDA #$FF" }
+      ]
+    },
+    {
+      "name": "jmp",
+      "status": "timeout",
+      "duration": 2.000,
+      "cases": [
+        { "name": "jmp", "status": "timeout", "duration": 2.000, "message": "no result within 2 seconds." }
+      ]
+    },
+    {
+      "name": "st",
+      "status": "failed",
+      "duration": 0.000,
+      "cases": [
+        { "name": "st", "status": "failed", "duration": 0.000, "message": "did not run." }
+      ]
+    }
+  ]
+}
+"#;
+        assert_eq!(render(&report()), expected);
+    }
+
+    #[test]
+    fn renders_an_empty_report() {
+        let expected = "{\n  \"tests\": 0,\n  \"failures\": 0,\n  \"duration\": 0.000,\n  \"suites\": [\n  ]\n}\n";
+        assert_eq!(render(&Report::default()), expected);
+    }
+}
@@ -0,0 +1,113 @@
+use super::{Report, Status};
+use std::fmt::Write;
+
+/// JUnit XML as read by most CI dashboards.
+pub fn render(report: &Report) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        xml,
+        "<testsuites name=\"fc2_tests\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+        report.tests(),
+        report.failures(),
+        report.duration().as_secs_f64()
+    )
+    .unwrap();
+    for suite in &report.suites {
+        writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+            escape(&suite.name),
+            suite.cases.len(),
+            suite.failures(),
+            suite.duration.as_secs_f64()
+        )
+        .unwrap();
+        for case in &suite.cases {
+            write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                escape(&case.name),
+                escape(&suite.name),
+                case.duration.as_secs_f64()
+            )
+            .unwrap();
+            match case.status {
+                Status::Passed => xml.push_str("/>\n"),
+                Status::Failed | Status::Timeout => {
+                    writeln!(
+                        xml,
+                        ">\n      <failure type=\"{}\" message=\"{}\">{}</failure>\n    </testcase>",
+                        case.status.as_str(),
+                        escape(case.message.as_deref().unwrap_or_default()),
+                        escape(&case.output.join("\n"))
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::report;
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(escape("LDA #$FF"), "LDA #$FF");
+        assert_eq!(
+            escape(r#"<a href="x">'b' & c</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&apos;b&apos; &amp; c&lt;/a&gt;"
+        );
+        assert_eq!(escape("&amp;"), "&amp;amp;");
+    }
+
+    #[test]
+    fn renders_every_status() {
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="fc2_tests" tests="5" failures="3" time="4.750">
+  <testsuite name="adc" tests="1" failures="0" time="1.500">
+    <testcase name="test_adc" classname="adc" time="1.500"/>
+  </testsuite>
+  <testsuite name="lda" tests="2" failures="1" time="1.250">
+    <testcase name="test_ok" classname="lda" time="0.250"/>
+    <testcase name="test_lda" classname="lda" time="1.000">
+      <failure type="failed" message="test_lda failed at register P. expect value is [A4], but return value is [24]. expected N set, got N clear">inst LDA #$FF
+usart read &quot;&lt;A9&gt;&quot; &amp; more
+test_lda failed at register P. expect value is [A4], but return value is [24]. expected N set, got N clear</failure>
+    </testcase>
+  </testsuite>
+  <testsuite name="jmp" tests="1" failures="1" time="2.000">
+    <testcase name="jmp" classname="jmp" time="2.000">
+      <failure type="timeout" message="no result within 2 seconds.">no result within 2 seconds.</failure>
+    </testcase>
+  </testsuite>
+  <testsuite name="st" tests="1" failures="1" time="0.000">
+    <testcase name="st" classname="st" time="0.000">
+      <failure type="failed" message="did not run.">did not run.</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#;
+        assert_eq!(render(&report()), expected);
+    }
+}
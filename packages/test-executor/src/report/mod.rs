@@ -0,0 +1,640 @@
+//! Results of a run, built from the lines `TestRunner` logs through defmt.
+
+pub mod json;
+pub mod junit;
+
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Passed,
+    Failed,
+    /// The suite stopped logging and probe-run was killed.
+    Timeout,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Passed => "passed",
+            Status::Failed => "failed",
+            Status::Timeout => "timeout",
+        }
+    }
+}
+
+/// One `test_*` function.
+#[derive(Debug, Clone)]
+pub struct CaseReport {
+    pub name: String,
+    pub status: Status,
+    /// Time since the previous test or the start of the suite.
+    pub duration: Duration,
+    /// Line that explains the failure, usually the `CheckError`.
+    pub message: Option<String>,
+    /// Values compared by the failed check, parsed from `message`.
+    pub mismatch: Option<Mismatch>,
+    /// Disassembly of the instruction the failed test last sent, or of the nestest.log line
+    /// a checkpoint run stopped at, e.g. `LDA #$FF`.
+    pub instruction: Option<String>,
+    /// Everything the test logged.
+    pub output: Vec<String>,
+}
+
+/// `expect value is <expected>, but return value is <actual>` of a failed check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// What `CheckError` was looking at, e.g. `register A`. `None` for the lines the
+    /// check helpers log before returning the error.
+    pub check: Option<String>,
+    pub expected: String,
+    pub actual: String,
+    /// Differing flags of a P register check, e.g. `expected N set, got N clear`.
+    pub flags: Option<String>,
+}
+
+impl Mismatch {
+    /// Parses either `<test> failed at <check>. expect value is ..., but return value is ...`
+    /// or `test failed. expect value is ..., but return value is ...`, where a P register
+    /// check adds `. <flag diff>` to the first form.
+    pub fn parse(message: &str) -> Option<Self> {
+        let (head, values) = message.split_once("expect value is ")?;
+        let (expected, actual) = values.split_once(", but return value is ")?;
+        let (actual, flags) = match actual.split_once(". ") {
+            Some((actual, flags)) => (actual, Some(flags.trim().to_string())),
+            None => (actual, None),
+        };
+        let check = head
+            .split_once(" failed at ")
+            .map(|(_, check)| check.trim_end().trim_end_matches('.').to_string());
+        Some(Self {
+            check,
+            expected: expected.trim().to_string(),
+            actual: actual.trim().to_string(),
+            flags,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SuiteReport {
+    pub name: String,
+    pub status: Status,
+    pub duration: Duration,
+    pub cases: Vec<CaseReport>,
+    /// Every line probe-run printed for the suite, location lines included.
+    pub log: Vec<String>,
+}
+
+impl SuiteReport {
+    pub fn failures(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| case.status != Status::Passed)
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub suites: Vec<SuiteReport>,
+}
+
+impl Report {
+    pub fn tests(&self) -> usize {
+        self.suites.iter().map(|suite| suite.cases.len()).sum()
+    }
+
+    pub fn failures(&self) -> usize {
+        self.suites.iter().map(SuiteReport::failures).sum()
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.suites.iter().map(|suite| suite.duration).sum()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.suites
+            .iter()
+            .all(|suite| suite.status == Status::Passed)
+    }
+}
+
+/// Builds a [`Report`] from probe-run stdout, one line at a time.
+pub struct Collector {
+    report: Report,
+    suite_started: Instant,
+    case_started: Instant,
+    output: Vec<String>,
+    /// Raw lines since the last suite ended.
+    log: Vec<String>,
+    running: bool,
+}
+
+impl Collector {
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            report: Report::default(),
+            suite_started: now,
+            case_started: now,
+            output: vec![],
+            log: vec![],
+            running: false,
+        }
+    }
+
+    pub fn line(&mut self, line: &str) {
+        self.log.push(line.to_string());
+        let Some(message) = defmt_message(line) else {
+            return;
+        };
+        let now = Instant::now();
+        if let Some(name) = marker(message, "suite ", " started.") {
+            self.suite_started = now;
+            self.case_started = now;
+            self.output.clear();
+            self.running = true;
+            self.report.suites.push(SuiteReport {
+                name: name.to_string(),
+                status: Status::Passed,
+                duration: Duration::ZERO,
+                cases: vec![],
+                log: vec![],
+            });
+        } else if let Some((name, status)) = status_marker(message, "suite ") {
+            // an unknown suite fails without being started.
+            if self.report.suites.last().map(|suite| suite.name.as_str()) != Some(name) {
+                self.suite_started = now;
+                self.report.suites.push(SuiteReport {
+                    name: name.to_string(),
+                    status,
+                    duration: Duration::ZERO,
+                    cases: vec![],
+                    log: vec![],
+                });
+            }
+            self.running = false;
+            let output = std::mem::take(&mut self.output);
+            let suite = self.report.suites.last_mut().unwrap();
+            suite.status = status;
+            suite.duration = now - self.suite_started;
+            suite.log.append(&mut self.log);
+            // keeps a suite that failed outside of any test visible as a failed case.
+            if status == Status::Failed && suite.failures() == 0 {
+                let message = failure_message(&output);
+                suite.cases.push(CaseReport {
+                    name: name.to_string(),
+                    status,
+                    duration: suite.duration,
+                    mismatch: message.as_deref().and_then(Mismatch::parse),
+                    instruction: failure_instruction(&output),
+                    message,
+                    output,
+                });
+            }
+        } else if let Some((name, status)) = status_marker(message, "test ") {
+            let output = std::mem::take(&mut self.output);
+            let message = match status {
+                Status::Passed => None,
+                Status::Failed | Status::Timeout => failure_message(&output),
+            };
+            let case = CaseReport {
+                name: name.to_string(),
+                status,
+                duration: now - self.case_started,
+                mismatch: message.as_deref().and_then(Mismatch::parse),
+                instruction: match status {
+                    Status::Passed => None,
+                    Status::Failed | Status::Timeout => failure_instruction(&output),
+                },
+                message,
+                output,
+            };
+            self.case_started = now;
+            match self.report.suites.last_mut() {
+                Some(suite) => suite.cases.push(case),
+                None => self.report.suites.push(SuiteReport {
+                    name: "fc2_tests".to_string(),
+                    status,
+                    duration: Duration::ZERO,
+                    cases: vec![case],
+                    log: vec![],
+                }),
+            }
+        } else {
+            self.output.push(message.to_string());
+        }
+    }
+
+    /// Suite that started and has not passed or failed yet.
+    pub fn running(&self) -> Option<&str> {
+        match self.running {
+            true => self.report.suites.last().map(|suite| suite.name.as_str()),
+            false => None,
+        }
+    }
+
+    /// Names of the suites that passed or failed so far.
+    pub fn finished(&self) -> impl Iterator<Item = &str> {
+        let running = self.running().is_some() as usize;
+        let finished = self.report.suites.len() - running;
+        self.report.suites[..finished]
+            .iter()
+            .map(|suite| suite.name.as_str())
+    }
+
+    /// Ends the running suite with `status` when probe-run stopped in the middle of it.
+    pub fn abort(&mut self, status: Status, message: String) {
+        if !self.running {
+            return;
+        }
+        self.running = false;
+        let now = Instant::now();
+        let mut output = std::mem::take(&mut self.output);
+        output.push(message.clone());
+        self.log.push(message.clone());
+        let suite = self.report.suites.last_mut().unwrap();
+        suite.status = status;
+        suite.duration = now - self.suite_started;
+        suite.log.append(&mut self.log);
+        suite.cases.push(CaseReport {
+            name: suite.name.clone(),
+            status,
+            duration: now - self.case_started,
+            message: Some(message),
+            mismatch: None,
+            instruction: None,
+            output,
+        });
+    }
+
+    /// Records a suite that never got to run.
+    pub fn skip(&mut self, name: &str, status: Status, message: String) {
+        self.report.suites.push(SuiteReport {
+            name: name.to_string(),
+            status,
+            duration: Duration::ZERO,
+            cases: vec![CaseReport {
+                name: name.to_string(),
+                status,
+                duration: Duration::ZERO,
+                message: Some(message.clone()),
+                mismatch: None,
+                instruction: None,
+                output: vec![message.clone()],
+            }],
+            log: vec![message],
+        });
+    }
+
+    pub fn finish(self) -> Report {
+        self.report
+    }
+}
+
+impl Default for Collector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Text of a defmt log line as printed by probe-run, e.g. `INFO  suite adc started.`.
+/// Location lines and host messages give `None`.
+fn defmt_message(line: &str) -> Option<&str> {
+    let line = line.trim();
+    let (level, message) = line.split_once(char::is_whitespace)?;
+    match level {
+        "TRACE" | "DEBUG" | "INFO" | "WARN" | "ERROR" => Some(message.trim()),
+        _ => None,
+    }
+}
+
+/// The `CheckError` line of a failure, or the last value comparison, or the last line.
+fn failure_message(output: &[String]) -> Option<String> {
+    output
+        .iter()
+        .rev()
+        .find(|line| line.contains(" failed at "))
+        .or_else(|| {
+            output
+                .iter()
+                .rev()
+                .find(|line| line.contains("expect value is "))
+        })
+        .or(output.last())
+        .cloned()
+}
+
+/// `inst LDA #$FF` logged by `send_command`, or `first mismatch at nestest.log line 3289:
+/// DB25 INC $0400` logged by a checkpoint run, whichever came last.
+fn failure_instruction(output: &[String]) -> Option<String> {
+    output.iter().rev().find_map(|line| {
+        line.strip_prefix("inst ")
+            .or_else(|| line.split_once("nestest.log ").map(|(_, rest)| rest))
+            .map(str::to_string)
+    })
+}
+
+fn marker<'a>(message: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    message.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+fn status_marker<'a>(message: &'a str, prefix: &str) -> Option<(&'a str, Status)> {
+    if let Some(name) = marker(message, prefix, " passed.") {
+        return Some((name, Status::Passed));
+    }
+    marker(message, prefix, " failed.").map(|name| (name, Status::Failed))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn case(name: &str, status: Status, millis: u64, output: &[&str]) -> CaseReport {
+        let output: Vec<String> = output.iter().map(|line| line.to_string()).collect();
+        let message = match status {
+            Status::Passed => None,
+            Status::Failed | Status::Timeout => failure_message(&output),
+        };
+        CaseReport {
+            name: name.to_string(),
+            status,
+            duration: Duration::from_millis(millis),
+            mismatch: message.as_deref().and_then(Mismatch::parse),
+            instruction: failure_instruction(&output),
+            message,
+            output,
+        }
+    }
+
+    fn suite(name: &str, status: Status, cases: Vec<CaseReport>) -> SuiteReport {
+        SuiteReport {
+            name: name.to_string(),
+            status,
+            duration: cases.iter().map(|case| case.duration).sum(),
+            cases,
+            log: vec![],
+        }
+    }
+
+    /// One suite that passed, one that failed a P check, one that timed out and one that
+    /// did not run.
+    pub(crate) fn report() -> Report {
+        Report {
+            suites: vec![
+                suite(
+                    "adc",
+                    Status::Passed,
+                    vec![case("test_adc", Status::Passed, 1500, &[])],
+                ),
+                suite(
+                    "lda",
+                    Status::Failed,
+                    vec![
+                        case("test_ok", Status::Passed, 250, &[]),
+                        case(
+                            "test_lda",
+                            Status::Failed,
+                            1000,
+                            &[
+                                "inst LDA #$FF",
+                                "usart read \"<A9>\" & more",
+                                "test_lda failed at register P. expect value is [A4], but return value is [24]. expected N set, got N clear",
+                            ],
+                        ),
+                    ],
+                ),
+                suite(
+                    "jmp",
+                    Status::Timeout,
+                    vec![case(
+                        "jmp",
+                        Status::Timeout,
+                        2000,
+                        &["no result within 2 seconds."],
+                    )],
+                ),
+                suite(
+                    "st",
+                    Status::Failed,
+                    vec![case("st", Status::Failed, 0, &["did not run."])],
+                ),
+            ],
+        }
+    }
+
+    fn collect(lines: &[&str]) -> Report {
+        let mut collector = Collector::new();
+        for line in lines {
+            collector.line(line);
+        }
+        collector.finish()
+    }
+
+    #[test]
+    fn parses_a_check_error_with_a_flag_diff() {
+        let mismatch = Mismatch::parse(
+            "test_lda failed at register P. expect value is [A4], but return value is [24]. expected N set, got N clear",
+        );
+        assert_eq!(
+            mismatch,
+            Some(Mismatch {
+                check: Some("register P".to_string()),
+                expected: "[A4]".to_string(),
+                actual: "[24]".to_string(),
+                flags: Some("expected N set, got N clear".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_a_plain_comparison() {
+        let mismatch =
+            Mismatch::parse("test failed. expect value is [0xA4], but return value is [0x24]");
+        assert_eq!(
+            mismatch,
+            Some(Mismatch {
+                check: None,
+                expected: "[0xA4]".to_string(),
+                actual: "[0x24]".to_string(),
+                flags: None,
+            })
+        );
+        assert_eq!(
+            Mismatch::parse("test_lda failed at state. A: expected 4C, got 00"),
+            None
+        );
+        assert_eq!(Mismatch::parse("expect value is [00]"), None);
+    }
+
+    #[test]
+    fn failure_message_prefers_the_check_error() {
+        let output = |lines: &[&str]| {
+            lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+        };
+        let check_error =
+            "test_lda failed at register A. expect value is [FF], but return value is [00]";
+        let comparison = "test failed. expect value is [0xFF], but return value is [0x00]";
+        assert_eq!(
+            failure_message(&output(&[comparison, check_error, "fpga reset"])).as_deref(),
+            Some(check_error)
+        );
+        assert_eq!(
+            failure_message(&output(&[comparison, "fpga reset"])).as_deref(),
+            Some(comparison)
+        );
+        assert_eq!(
+            failure_message(&output(&["usart write data(0xA9)", "panicked"])).as_deref(),
+            Some("panicked")
+        );
+        assert_eq!(failure_message(&[]), None);
+    }
+
+    #[test]
+    fn failure_instruction_takes_the_last_one() {
+        let output = |lines: &[&str]| {
+            lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            failure_instruction(&output(&["inst LDA #$FF", "inst STA $0400", "check"])).as_deref(),
+            Some("STA $0400")
+        );
+        assert_eq!(
+            failure_instruction(&output(&[
+                "inst JMP $C000",
+                "first mismatch at nestest.log line 3289: DB25 INC $0400",
+            ]))
+            .as_deref(),
+            Some("line 3289: DB25 INC $0400")
+        );
+        assert_eq!(
+            failure_instruction(&output(&["usart write data(0xA9)"])),
+            None
+        );
+    }
+
+    #[test]
+    fn collects_a_failed_check() {
+        let report = collect(&[
+            "(HOST) INFO  flashing program",
+            "INFO  suite ld started.",
+            "└─ embassy_fc2_app::middleware::runner::{impl#1}::run_suite @ src/middleware/runner.rs:93",
+            "INFO  test test_ldx passed.",
+            "INFO  inst LDA #$FF",
+            "INFO  test failed. expect value is [0xA4], but return value is [0x24]",
+            "INFO  expected N set, got N clear",
+            "INFO  test_lda failed at register P. expect value is [A4], but return value is [24]. expected N set, got N clear",
+            "└─ embassy_fc2_app::middleware::runner::{impl#1}::run @ src/middleware/runner.rs:47",
+            "INFO  test test_lda failed.",
+            "INFO  fpga reset after test_lda.",
+            "INFO  suite ld failed.",
+        ]);
+        let [suite] = &report.suites[..] else {
+            panic!("{:?}", report.suites);
+        };
+        assert_eq!((suite.name.as_str(), suite.status), ("ld", Status::Failed));
+        assert_eq!(suite.log.len(), 12);
+        let [passed, failed] = &suite.cases[..] else {
+            panic!("{:?}", suite.cases);
+        };
+        assert_eq!(
+            (passed.name.as_str(), passed.status),
+            ("test_ldx", Status::Passed)
+        );
+        assert_eq!(
+            (failed.name.as_str(), failed.status),
+            ("test_lda", Status::Failed)
+        );
+        let mismatch = failed.mismatch.as_ref().unwrap();
+        assert_eq!(mismatch.check.as_deref(), Some("register P"));
+        assert_eq!(
+            mismatch.flags.as_deref(),
+            Some("expected N set, got N clear")
+        );
+        assert_eq!(failed.instruction.as_deref(), Some("LDA #$FF"));
+        // location lines and host messages are not part of the output.
+        assert_eq!(failed.output.len(), 4);
+        assert!(failed.output.iter().all(|line| !line.contains("└─")));
+    }
+
+    #[test]
+    fn collects_a_state_diff() {
+        let report = collect(&[
+            "INFO  suite sequence started.",
+            "INFO  first mismatch at nestest.log line 3289: DB25 INC $0400",
+            "INFO  test_inst_sequence failed at state. P: expected V clear, got V set",
+            "INFO  test test_inst_sequence failed.",
+            "INFO  suite sequence failed.",
+        ]);
+        let case = &report.suites[0].cases[0];
+        assert_eq!(
+            case.message.as_deref(),
+            Some("test_inst_sequence failed at state. P: expected V clear, got V set")
+        );
+        assert_eq!(case.mismatch, None);
+        assert_eq!(
+            case.instruction.as_deref(),
+            Some("line 3289: DB25 INC $0400")
+        );
+    }
+
+    #[test]
+    fn collects_failures_outside_of_a_test() {
+        let report = collect(&[
+            "INFO  suite missing failed.",
+            "INFO  suite jmp started.",
+            "INFO  test failed. expect value is [0x01], but return value is [0x00]",
+            "INFO  suite jmp failed.",
+            "INFO  test test_orphan passed.",
+        ]);
+        let names: Vec<_> = report
+            .suites
+            .iter()
+            .map(|suite| suite.name.as_str())
+            .collect();
+        assert_eq!(names, ["missing", "jmp"]);
+        // an unknown suite fails without a started marker or any output.
+        let missing = &report.suites[0];
+        assert_eq!(missing.status, Status::Failed);
+        assert_eq!(missing.cases[0].message, None);
+        // the suite itself stands in for the test that never reported.
+        let jmp = &report.suites[1];
+        assert_eq!(
+            (jmp.cases[0].name.as_str(), jmp.cases[0].status),
+            ("jmp", Status::Failed)
+        );
+        assert_eq!(jmp.cases[0].mismatch.as_ref().unwrap().expected, "[0x01]");
+        // a test after the last suite ended is added to it.
+        assert_eq!(jmp.cases[1].name, "test_orphan");
+
+        let report = collect(&["INFO  test test_alone failed."]);
+        assert_eq!(report.suites[0].name, "fc2_tests");
+        assert_eq!(report.suites[0].cases[0].name, "test_alone");
+    }
+
+    #[test]
+    fn skips_non_defmt_lines() {
+        assert_eq!(
+            defmt_message("INFO  suite adc started."),
+            Some("suite adc started.")
+        );
+        assert_eq!(
+            defmt_message("  ERROR panicked at 'x'"),
+            Some("panicked at 'x'")
+        );
+        assert_eq!(
+            defmt_message("└─ fc2_tests::__cortex_m_rt_main @ src/bin/fc2_tests.rs:34"),
+            None
+        );
+        assert_eq!(defmt_message("(HOST) INFO  flashing program"), None);
+        assert_eq!(defmt_message("INFO"), None);
+        assert_eq!(defmt_message(""), None);
+    }
+}
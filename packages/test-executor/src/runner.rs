@@ -0,0 +1,55 @@
+//! Command that builds, flashes, and runs `fc2_tests`, printing the defmt log on stdout.
+
+use std::process::{Command, Stdio};
+
+/// Program and arguments started for every flash, with extra environment variables.
+///
+/// Anything that prints the `TestRunner` markers for the suites in `FC2_SUITES` works, such
+/// as `fake_runner.sh` in this package.
+#[derive(Debug, Clone)]
+pub struct Runner {
+    pub program: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        // probe-run is the cargo runner of stm32l476rg.
+        Self::parse("cargo run --bin fc2_tests").unwrap()
+    }
+}
+
+impl Runner {
+    /// Splits `command` on whitespace into the program and its arguments.
+    pub fn parse(command: &str) -> Result<Self, String> {
+        let mut words = command.split_whitespace().map(str::to_string);
+        Ok(Self {
+            program: words.next().ok_or("runner command is empty")?,
+            args: words.collect(),
+            env: vec![],
+        })
+    }
+
+    /// Adds a `KEY=VALUE` variable.
+    pub fn push_env(&mut self, var: &str) -> Result<(), String> {
+        let (key, value) = var
+            .split_once('=')
+            .filter(|(key, _)| !key.is_empty())
+            .ok_or_else(|| format!("invalid --env `{}`", var))?;
+        self.env.push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+
+    /// Command running `suites`, a comma separated list, with stdout piped.
+    pub fn command(&self, suites: &str) -> Command {
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .envs(self.env.iter().map(|(key, value)| (key, value)))
+            // read by the firmware through `option_env!`, so it takes effect on rebuild.
+            .env("FC2_SUITES", suites)
+            .stdout(Stdio::piped());
+        command
+    }
+}
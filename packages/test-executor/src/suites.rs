@@ -0,0 +1,197 @@
+//! Suites registered in the firmware and the ones selected on the command line.
+
+use std::{fs, io, path::Path};
+
+/// Suites that run data generated by another tool, `dump_sequence` for `sequence`. They are
+/// only selected when named exactly or with `--generated`.
+pub const GENERATED: &[&str] = &["sequence"];
+
+/// Suite names in the order `fc2_suites::registry` runs them, read from its source under
+/// `root`.
+pub fn discover(root: &Path) -> io::Result<Vec<String>> {
+    let registry = fs::read_to_string(root.join("src/lib.rs"))?;
+    Ok(registry
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("Suite::new(\""))
+        .filter_map(|line| line.split_once('"').map(|(name, _)| name.to_string()))
+        .collect())
+}
+
+pub fn is_generated(name: &str) -> bool {
+    GENERATED.contains(&name)
+}
+
+/// Which registered suites to run.
+#[derive(Debug, Default)]
+pub struct Selection {
+    /// Names or globs to run, everything when empty.
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    /// Selects generated suites by glob as well.
+    pub generated: bool,
+}
+
+impl Selection {
+    /// Registered suites in registry order, or the first include pattern selecting none.
+    pub fn select<'a>(&self, registered: &'a [String]) -> Result<Vec<&'a str>, String> {
+        if let Some(pattern) = self.include.iter().find(|pattern| {
+            !registered
+                .iter()
+                .any(|name| self.selected_by(pattern, name))
+        }) {
+            return Err(format!("no suite matches `{}`", pattern));
+        }
+        Ok(registered
+            .iter()
+            .map(String::as_str)
+            .filter(|name| self.included(name))
+            .filter(|name| !self.exclude.iter().any(|pattern| glob(pattern, name)))
+            .collect())
+    }
+
+    fn included(&self, name: &str) -> bool {
+        match self.include.is_empty() {
+            true => self.generated || !is_generated(name),
+            false => self
+                .include
+                .iter()
+                .any(|pattern| self.selected_by(pattern, name)),
+        }
+    }
+
+    /// Whether the include `pattern` picks `name`. A glob skips generated suites unless
+    /// `--generated` is given.
+    fn selected_by(&self, pattern: &str, name: &str) -> bool {
+        pattern == name || (glob(pattern, name) && (self.generated || !is_generated(name)))
+    }
+}
+
+/// Matches `name` against `pattern`, where `*` stands for any run of characters and `?` for
+/// one character.
+pub fn glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // position of the last `*` and the name position it was tried at.
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registered() -> Vec<String> {
+        ["adc", "lda", "ldx", "ld_mock", "sequence", "init_sequence"]
+            .map(str::to_string)
+            .to_vec()
+    }
+
+    fn select(include: &[&str], exclude: &[&str], generated: bool) -> Result<Vec<String>, String> {
+        let selection = Selection {
+            include: include.iter().map(|p| p.to_string()).collect(),
+            exclude: exclude.iter().map(|p| p.to_string()).collect(),
+            generated,
+        };
+        let registered = registered();
+        let selected = selection.select(&registered)?;
+        Ok(selected.into_iter().map(str::to_string).collect())
+    }
+
+    #[test]
+    fn glob_matches_star_and_question_mark() {
+        assert!(glob("adc", "adc"));
+        assert!(!glob("adc", "adcx"));
+        assert!(glob("*", ""));
+        assert!(glob("l*", "ldx"));
+        assert!(glob("l?x", "ldx"));
+        assert!(!glob("l?x", "lx"));
+        assert!(glob("*_*", "init_sequence"));
+        assert!(glob("**a", "a"));
+        assert!(!glob("?", ""));
+    }
+
+    #[test]
+    fn glob_backtracks_after_a_star() {
+        // the first `s` after `*` is not the one that ends the name.
+        assert!(glob("*seq*e", "init_sequence"));
+        assert!(glob("*a*b", "aaabab"));
+        assert!(!glob("*a*b", "aaaba"));
+        assert!(glob("l*_m?ck", "ld_l_mock"));
+        assert!(!glob("*ld", "ld_mock"));
+    }
+
+    #[test]
+    fn selects_in_registry_order() {
+        assert_eq!(
+            select(&[], &[], false).unwrap(),
+            ["adc", "lda", "ldx", "ld_mock", "init_sequence"]
+        );
+        assert_eq!(select(&["ldx", "a*"], &[], false).unwrap(), ["adc", "ldx"]);
+    }
+
+    #[test]
+    fn selects_generated_suites_by_exact_name() {
+        assert_eq!(select(&["sequence"], &[], false).unwrap(), ["sequence"]);
+        assert_eq!(
+            select(&["*sequence"], &[], false).unwrap(),
+            ["init_sequence"]
+        );
+        assert_eq!(
+            select(&["*sequence"], &[], true).unwrap(),
+            ["sequence", "init_sequence"]
+        );
+        assert_eq!(select(&[], &[], true).unwrap().len(), 6);
+    }
+
+    #[test]
+    fn excludes_after_including() {
+        assert_eq!(select(&["l*"], &["ld?"], false).unwrap(), ["ld_mock"]);
+        assert_eq!(select(&[], &["*_*"], false).unwrap(), ["adc", "lda", "ldx"]);
+        // an exclude that matches nothing is not an error.
+        assert_eq!(select(&["adc"], &["x*"], false).unwrap(), ["adc"]);
+    }
+
+    #[test]
+    fn include_matching_nothing_is_an_error() {
+        assert_eq!(
+            select(&["adc", "st*"], &[], false),
+            Err("no suite matches `st*`".to_string())
+        );
+        // only generated suites match, which a glob does not pick without --generated.
+        assert_eq!(
+            select(&["seq*"], &[], false),
+            Err("no suite matches `seq*`".to_string())
+        );
+        assert_eq!(select(&["seq*"], &[], true).unwrap(), ["sequence"]);
+    }
+
+    #[test]
+    fn discovers_the_registry() {
+        let suites = discover(Path::new("../fc2_suites")).unwrap();
+        assert_eq!(suites.len(), 23);
+        assert_eq!(suites.first().map(String::as_str), Some("adc"));
+        assert!(suites.iter().any(|name| name == "sequence"));
+        assert!(discover(Path::new("missing")).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+use test_executor::exe_all;
+use test_executor::report::{Report, Status};
+use test_executor::runner::Runner;
+
+const FAKE_RUNNER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fake_runner.sh");
+
+/// Short enough for a hung suite not to hold the test up.
+const LIMIT: Duration = Duration::from_secs(1);
+
+fn fake_runner(env: &[&str]) -> Runner {
+    let mut runner = Runner::parse(FAKE_RUNNER).unwrap();
+    for var in env {
+        runner.push_env(var).unwrap();
+    }
+    runner
+}
+
+async fn run(env: &[&str], suites: &[&str]) -> Report {
+    let suites = suites.iter().map(|name| name.to_string()).collect();
+    exe_all(&fake_runner(env), suites, LIMIT).await
+}
+
+fn statuses(report: &Report) -> Vec<(&str, Status)> {
+    report
+        .suites
+        .iter()
+        .map(|suite| (suite.name.as_str(), suite.status))
+        .collect()
+}
+
+#[tokio::test]
+async fn every_suite_passes() {
+    let report = run(&[], &["adc", "lda", "jmp"]).await;
+    assert_eq!(
+        statuses(&report),
+        [
+            ("adc", Status::Passed),
+            ("lda", Status::Passed),
+            ("jmp", Status::Passed)
+        ]
+    );
+    assert!(report.passed());
+    assert_eq!((report.tests(), report.failures()), (3, 0));
+}
+
+#[tokio::test]
+async fn failed_check_is_reported_and_the_rest_run() {
+    let report = run(&["FAKE_FAIL=lda"], &["adc", "lda", "jmp"]).await;
+    assert_eq!(
+        statuses(&report),
+        [
+            ("adc", Status::Passed),
+            ("lda", Status::Failed),
+            ("jmp", Status::Passed)
+        ]
+    );
+    assert!(!report.passed());
+    let case = &report.suites[1].cases[0];
+    assert_eq!(case.name, "test_lda");
+    let mismatch = case.mismatch.as_ref().unwrap();
+    assert_eq!(mismatch.check.as_deref(), Some("register P"));
+    assert_eq!(
+        (mismatch.expected.as_str(), mismatch.actual.as_str()),
+        ("[A4]", "[24]")
+    );
+    assert_eq!(case.instruction.as_deref(), Some("LDA #$FF"));
+}
+
+#[tokio::test]
+async fn crash_fails_the_suite_and_flashes_again_with_the_rest() {
+    let report = run(&["FAKE_CRASH=lda"], &["adc", "lda", "jmp"]).await;
+    assert_eq!(
+        statuses(&report),
+        [
+            ("adc", Status::Passed),
+            ("lda", Status::Failed),
+            ("jmp", Status::Passed)
+        ]
+    );
+    let case = &report.suites[1].cases[0];
+    assert_eq!(case.message.as_deref(), Some("runner exited."));
+}
+
+#[tokio::test]
+async fn hang_times_out_and_flashes_again_with_the_rest() {
+    let report = run(&["FAKE_HANG=adc"], &["adc", "lda", "jmp"]).await;
+    assert_eq!(
+        statuses(&report),
+        [
+            ("adc", Status::Timeout),
+            ("lda", Status::Passed),
+            ("jmp", Status::Passed)
+        ]
+    );
+    let case = &report.suites[0].cases[0];
+    assert_eq!(case.message.as_deref(), Some("no result within 1 seconds."));
+}
+
+#[tokio::test]
+async fn suites_no_flash_reaches_did_not_run() {
+    // the second flash gets through lda, and the third one runs nothing.
+    let report = run(
+        &["FAKE_HANG=adc", "FAKE_EXIT=jmp"],
+        &["adc", "lda", "jmp", "bit"],
+    )
+    .await;
+    assert_eq!(
+        statuses(&report),
+        [
+            ("adc", Status::Timeout),
+            ("lda", Status::Passed),
+            ("jmp", Status::Failed),
+            ("bit", Status::Failed)
+        ]
+    );
+    for suite in &report.suites[2..] {
+        assert_eq!(suite.cases.len(), 1);
+        assert_eq!(suite.cases[0].message.as_deref(), Some("did not run."));
+    }
+}
+
+/// Runs the executor binary against the fake runner and returns its exit code.
+fn executor(args: &[&str]) -> Option<i32> {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let status = Command::new(env!("CARGO_BIN_EXE_test-executor"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--runner", FAKE_RUNNER, "--timeout", "1", "--logs"])
+        .arg(dir.join("fc2-logs"))
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    status.code()
+}
+
+#[test]
+fn exit_code_follows_the_report() {
+    assert_eq!(executor(&["adc", "bit"]), Some(0));
+    assert_eq!(executor(&["--env", "FAKE_FAIL=bit", "adc", "bit"]), Some(1));
+    assert_eq!(executor(&["--env", "FAKE_HANG=adc", "adc", "bit"]), Some(1));
+    // nothing to run is a usage error rather than a failed run.
+    assert_eq!(executor(&["missing"]), Some(2));
+}